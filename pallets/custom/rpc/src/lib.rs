@@ -0,0 +1,196 @@
+//! RPC interface for the custom pallet.
+//!
+//! Serves the [`CounterApi`](pallet_custom_runtime_api::CounterApi) runtime API over JSON-RPC, so
+//! tooling can read the counter without computing storage keys for `state_getStorage`.
+//!
+//! ## Methods
+//!
+//! - `counter_getValue(at?)`: the value of the global counter.
+//! - `counter_getInteractions(who, at?)`: the number of times `who` changed the global counter.
+//! - `counter_subscribeValue()`: the current value of the global counter, followed by its new value
+//!   every time a new best block changes it. Cancelled with `counter_unsubscribeValue`.
+//!
+//! When `at` is omitted, the best block is queried.
+//!
+//! ## Usage
+//!
+//! Merge the module into the node's RPC module builder:
+//!
+//! ```ignore
+//! use pallet_custom_rpc::{Counter, CounterApiServer};
+//!
+//! module.merge(Counter::new(client.clone(), subscription_executor.clone()).into_rpc())?;
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use futures::{
+	future::{self, Either},
+	FutureExt, Stream, StreamExt,
+};
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::{ErrorObject, ErrorObjectOwned},
+	PendingSubscriptionSink, SubscriptionMessage,
+};
+use sc_client_api::BlockchainEvents;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::traits::SpawnNamed;
+use sp_runtime::{traits::Block as BlockT, Serialize};
+
+pub use pallet_custom_runtime_api::CounterApi as CounterRuntimeApi;
+
+#[rpc(client, server)]
+pub trait CounterApi<BlockHash, AccountId, CounterValue> {
+	/// The value of the global counter at block `at`, or at the best block.
+	#[method(name = "counter_getValue")]
+	fn value(&self, at: Option<BlockHash>) -> RpcResult<CounterValue>;
+
+	/// The number of times `who` changed the global counter at block `at`, or at the best
+	/// block.
+	#[method(name = "counter_getInteractions")]
+	fn interactions(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<u32>;
+
+	/// Subscribe to the value of the global counter on the best chain.
+	///
+	/// Emits the current value first, then the new value each time a new best block changes it.
+	#[subscription(
+		name = "counter_subscribeValue" => "counter_value",
+		unsubscribe = "counter_unsubscribeValue",
+		item = CounterValue,
+	)]
+	fn subscribe_value(&self);
+}
+
+/// Provides RPC methods to query the custom pallet.
+pub struct Counter<C, Block> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	/// Spawns the tasks driving subscriptions.
+	executor: Arc<dyn SpawnNamed>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Counter<C, Block> {
+	/// Creates a new instance of the Counter RPC helper.
+	pub fn new(client: Arc<C>, executor: Arc<dyn SpawnNamed>) -> Self {
+		Self { client, executor, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+fn runtime_error(error: impl ToString) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query the counter.",
+		Some(error.to_string()),
+	)
+}
+
+impl<C, Block, AccountId, CounterValue>
+	CounterApiServer<<Block as BlockT>::Hash, AccountId, CounterValue> for Counter<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block>
+		+ HeaderBackend<Block>
+		+ BlockchainEvents<Block>
+		+ Send
+		+ Sync
+		+ 'static,
+	C::Api: CounterRuntimeApi<Block, AccountId, CounterValue>,
+	AccountId: Codec + Send + Sync + 'static,
+	CounterValue: Codec + Serialize + PartialEq + Copy + Send + Sync + 'static,
+{
+	fn value(&self, at: Option<Block::Hash>) -> RpcResult<CounterValue> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client.runtime_api().counter_value(at_hash).map_err(runtime_error)
+	}
+
+	fn interactions(&self, who: AccountId, at: Option<Block::Hash>) -> RpcResult<u32> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client.runtime_api().interactions_of(at_hash, who).map_err(runtime_error)
+	}
+
+	fn subscribe_value(&self, pending: PendingSubscriptionSink) {
+		let initial =
+			match CounterApiServer::<Block::Hash, AccountId, CounterValue>::value(self, None) {
+				Ok(initial) => initial,
+				Err(error) => {
+					self.spawn(pending.reject(error).boxed());
+					return
+				},
+			};
+
+		let client = self.client.clone();
+		let mut previous = initial;
+		let changes = self
+			.client
+			.import_notification_stream()
+			.filter(|notification| future::ready(notification.is_new_best))
+			.filter_map(move |notification| {
+				let value = client.runtime_api().counter_value(notification.hash);
+
+				future::ready(match value {
+					Ok(value) if value != previous => {
+						previous = value;
+						Some(value)
+					},
+					_ => None,
+				})
+			});
+
+		let stream = futures::stream::once(future::ready(initial)).chain(changes);
+		self.spawn(pipe_from_stream(pending, stream).boxed());
+	}
+}
+
+impl<C, Block> Counter<C, Block> {
+	fn spawn(&self, task: futures::future::BoxFuture<'static, ()>) {
+		self.executor.spawn("counter-rpc-subscription", Some("rpc"), task);
+	}
+}
+
+/// Accept `pending` and forward every item of `stream` to it until either side is closed.
+async fn pipe_from_stream<T: Serialize>(
+	pending: PendingSubscriptionSink,
+	stream: impl Stream<Item = T>,
+) {
+	let Ok(sink) = pending.accept().await else { return };
+	futures::pin_mut!(stream);
+
+	loop {
+		let closed = sink.closed();
+		futures::pin_mut!(closed);
+
+		match future::select(closed, stream.next()).await {
+			Either::Right((Some(value), _)) => {
+				let Ok(message) = SubscriptionMessage::from_json(&value) else { break };
+				if sink.send(message).await.is_err() {
+					break
+				}
+			},
+			_ => break,
+		}
+	}
+}
@@ -0,0 +1,266 @@
+use crate::{Counter, CounterApiServer, CounterRuntimeApi};
+use jsonrpsee::{core::EmptyServerParams, MethodsError, RpcModule};
+use sc_client_api::{
+	BlockImportNotification, BlockchainEvents, FinalityNotifications, ImportNotifications,
+	StorageEventStream, StorageKey, UnpinWorkerMessage,
+};
+use sc_utils::mpsc::{tracing_unbounded, TracingUnboundedSender};
+use sp_api::{ApiError, ApiRef, ProvideRuntimeApi};
+use sp_blockchain::{BlockStatus, HeaderBackend, Info};
+use sp_consensus::BlockOrigin;
+use sp_core::{testing::TaskExecutor, H256};
+use sp_runtime::{
+	generic,
+	traits::{BlakeTwo256, Header as HeaderT},
+	OpaqueExtrinsic,
+};
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex},
+};
+
+type Header = generic::Header<u64, BlakeTwo256>;
+type Block = generic::Block<Header, OpaqueExtrinsic>;
+type AccountId = u64;
+
+/// The counter state of every imported block.
+#[derive(Default)]
+struct Chain {
+	best: (u64, H256),
+	values: HashMap<H256, u32>,
+	interactions: HashMap<(H256, AccountId), u32>,
+}
+
+/// A client serving [`Chain`] through the counter runtime API.
+struct TestClient {
+	chain: Arc<Mutex<Chain>>,
+	import_sinks: Mutex<Vec<TracingUnboundedSender<BlockImportNotification<Block>>>>,
+	unpin_sender: TracingUnboundedSender<UnpinWorkerMessage<Block>>,
+}
+
+impl TestClient {
+	/// A client whose genesis block holds `genesis_value`.
+	fn new(genesis_value: u32) -> Self {
+		let (unpin_sender, _) = tracing_unbounded("test_unpin", 100);
+		let client =
+			Self { chain: Default::default(), import_sinks: Default::default(), unpin_sender };
+		client.import(0, genesis_value, true);
+		client
+	}
+
+	/// Import block `number` with counter value `value` and notify the import streams.
+	fn import(&self, number: u64, value: u32, is_new_best: bool) -> H256 {
+		let hash = block_hash(number);
+		{
+			let mut chain = self.chain.lock().unwrap();
+			chain.values.insert(hash, value);
+			if is_new_best {
+				chain.best = (number, hash);
+			}
+		}
+
+		let header = Header::new(
+			number,
+			Default::default(),
+			Default::default(),
+			Default::default(),
+			Default::default(),
+		);
+		self.import_sinks.lock().unwrap().retain(|sink| {
+			let notification = BlockImportNotification::new(
+				hash,
+				BlockOrigin::Own,
+				header.clone(),
+				is_new_best,
+				None,
+				self.unpin_sender.clone(),
+			);
+			sink.unbounded_send(notification).is_ok()
+		});
+
+		hash
+	}
+
+	fn set_interactions(&self, at: H256, who: AccountId, count: u32) {
+		self.chain.lock().unwrap().interactions.insert((at, who), count);
+	}
+}
+
+fn block_hash(number: u64) -> H256 {
+	H256::from_low_u64_be(number + 1)
+}
+
+struct TestApi {
+	chain: Arc<Mutex<Chain>>,
+}
+
+sp_api::mock_impl_runtime_apis! {
+	impl CounterRuntimeApi<Block, AccountId, u32> for TestApi {
+		#[advanced]
+		fn counter_value(&self, at: H256) -> Result<u32, ApiError> {
+			self.chain
+				.lock()
+				.unwrap()
+				.values
+				.get(&at)
+				.copied()
+				.ok_or_else(|| ApiError::UnknownBlock(format!("{at:?}")))
+		}
+
+		fn max_value() -> u32 {
+			u32::MAX
+		}
+
+		#[advanced]
+		fn interactions_of(&self, at: H256, who: AccountId) -> Result<u32, ApiError> {
+			Ok(self.chain.lock().unwrap().interactions.get(&(at, who)).copied().unwrap_or(0))
+		}
+
+		fn top_interactors(_limit: u32) -> Vec<(AccountId, u32)> {
+			Vec::new()
+		}
+	}
+}
+
+impl ProvideRuntimeApi<Block> for TestClient {
+	type Api = TestApi;
+
+	fn runtime_api(&self) -> ApiRef<'_, Self::Api> {
+		TestApi { chain: self.chain.clone() }.into()
+	}
+}
+
+impl HeaderBackend<Block> for TestClient {
+	fn header(&self, _hash: H256) -> sp_blockchain::Result<Option<Header>> {
+		Ok(None)
+	}
+
+	fn info(&self) -> Info<Block> {
+		let (best_number, best_hash) = self.chain.lock().unwrap().best;
+		Info {
+			best_hash,
+			best_number,
+			genesis_hash: block_hash(0),
+			finalized_hash: block_hash(0),
+			finalized_number: 0,
+			finalized_state: None,
+			number_leaves: 1,
+			block_gap: None,
+		}
+	}
+
+	fn status(&self, hash: H256) -> sp_blockchain::Result<BlockStatus> {
+		Ok(if self.chain.lock().unwrap().values.contains_key(&hash) {
+			BlockStatus::InChain
+		} else {
+			BlockStatus::Unknown
+		})
+	}
+
+	fn number(&self, _hash: H256) -> sp_blockchain::Result<Option<u64>> {
+		Ok(None)
+	}
+
+	fn hash(&self, _number: u64) -> sp_blockchain::Result<Option<H256>> {
+		Ok(None)
+	}
+}
+
+impl BlockchainEvents<Block> for TestClient {
+	fn import_notification_stream(&self) -> ImportNotifications<Block> {
+		let (sink, stream) = tracing_unbounded("test_import", 100);
+		self.import_sinks.lock().unwrap().push(sink);
+		stream
+	}
+
+	fn every_import_notification_stream(&self) -> ImportNotifications<Block> {
+		self.import_notification_stream()
+	}
+
+	fn finality_notification_stream(&self) -> FinalityNotifications<Block> {
+		tracing_unbounded("test_finality", 100).1
+	}
+
+	fn storage_changes_notification_stream(
+		&self,
+		_filter_keys: Option<&[StorageKey]>,
+		_child_filter_keys: Option<&[(StorageKey, Option<Vec<StorageKey>>)]>,
+	) -> sp_blockchain::Result<StorageEventStream<H256>> {
+		unimplemented!("not used by the counter RPC")
+	}
+}
+
+fn rpc_module(client: &Arc<TestClient>) -> RpcModule<Counter<TestClient, Block>> {
+	CounterApiServer::<H256, AccountId, u32>::into_rpc(Counter::new(
+		client.clone(),
+		Arc::new(TaskExecutor::new()),
+	))
+}
+
+#[tokio::test]
+async fn get_value_reads_the_best_block() {
+	let client = Arc::new(TestClient::new(3));
+	let module = rpc_module(&client);
+	assert_eq!(module.call::<_, u32>("counter_getValue", [None::<H256>]).await.unwrap(), 3);
+
+	client.import(1, 5, true);
+	assert_eq!(module.call::<_, u32>("counter_getValue", [None::<H256>]).await.unwrap(), 5);
+}
+
+#[tokio::test]
+async fn get_value_reads_the_given_block() {
+	let client = Arc::new(TestClient::new(3));
+	let module = rpc_module(&client);
+	client.import(1, 5, true);
+
+	let value = module.call::<_, u32>("counter_getValue", [Some(block_hash(0))]).await;
+	assert_eq!(value.unwrap(), 3);
+}
+
+#[tokio::test]
+async fn get_value_of_unknown_block_is_a_runtime_error() {
+	let client = Arc::new(TestClient::new(3));
+	let module = rpc_module(&client);
+
+	let error = module.call::<_, u32>("counter_getValue", [Some(block_hash(9))]).await;
+	match error {
+		Err(MethodsError::JsonRpc(error)) => {
+			assert_eq!(error.code(), 1);
+			assert_eq!(error.message(), "Unable to query the counter.");
+		},
+		other => panic!("expected a runtime error, got {other:?}"),
+	}
+}
+
+#[tokio::test]
+async fn get_interactions_works() {
+	let client = Arc::new(TestClient::new(0));
+	let module = rpc_module(&client);
+	let hash = client.import(1, 2, true);
+	client.set_interactions(hash, 1, 2);
+
+	let call = |who: AccountId, at: Option<H256>| {
+		module.call::<_, u32>("counter_getInteractions", (who, at))
+	};
+	assert_eq!(call(1, None).await.unwrap(), 2);
+	assert_eq!(call(2, None).await.unwrap(), 0);
+	assert_eq!(call(1, Some(block_hash(0))).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn subscribe_value_emits_changes_on_the_best_chain() {
+	let client = Arc::new(TestClient::new(3));
+	let module = rpc_module(&client);
+	let mut subscription = module
+		.subscribe_unbounded("counter_subscribeValue", EmptyServerParams::new())
+		.await
+		.unwrap();
+
+	assert_eq!(subscription.next::<u32>().await.unwrap().unwrap().0, 3);
+
+	// Neither an unchanged value nor a block off the best chain is emitted.
+	client.import(1, 3, true);
+	client.import(2, 4, false);
+	client.import(2, 6, true);
+	assert_eq!(subscription.next::<u32>().await.unwrap().unwrap().0, 6);
+}
@@ -0,0 +1,38 @@
+//! Runtime API definition for the custom pallet.
+//!
+//! Lets clients read the counter state without computing storage keys by hand.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+use sp_runtime::traits::NumberFor;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query the state of the counter pallet.
+	#[api_version(2)]
+	pub trait CounterApi<AccountId, CounterValue>
+	where
+		AccountId: Codec,
+		CounterValue: Codec,
+	{
+		/// The current value of the global counter.
+		fn counter_value() -> CounterValue;
+
+		/// The maximum value the global counter can hold.
+		fn max_value() -> CounterValue;
+
+		/// The number of times `who` incremented or decremented the global counter.
+		fn interactions_of(who: AccountId) -> u32;
+
+		/// Up to `limit` accounts with the most interactions, most active first.
+		fn top_interactors(limit: u32) -> Vec<(AccountId, u32)>;
+
+		/// The value the global counter had at the end of block `at`, or `None` if `at` precedes
+		/// the recorded history.
+		#[api_version(2)]
+		fn counter_value_at(at: NumberFor<Block>) -> Option<CounterValue>;
+	}
+}
@@ -0,0 +1,621 @@
+//! Benchmarking setup for pallet-custom.
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::{
+	traits::{
+		fungible::{Inspect, InspectHold, Mutate, MutateHold},
+		schedule::v3::Named as ScheduleNamed,
+		EnsureOrigin, Get, Hooks,
+	},
+	BoundedVec,
+};
+use frame_system::{
+	offchain::{AppCrypto, SignedPayload},
+	RawOrigin,
+};
+use sp_runtime::{
+	traits::{Bounded, Dispatchable, IdentifyAccount, One, StaticLookup, ValidateUnsigned, Zero},
+	transaction_validity::TransactionSource,
+	RuntimeAppPublic, Saturating,
+};
+
+/// Give `who` enough funds to cover one deposit of each kind.
+fn fund<T: Config>(who: &T::AccountId) {
+	let amount = T::Currency::minimum_balance()
+		.saturating_add(T::CounterDeposit::get())
+		.saturating_add(T::InteractionDeposit::get());
+	T::Currency::set_balance(who, amount);
+}
+
+fn full_name<T: Config>() -> BoundedVec<u8, T::MaxNameLength> {
+	let name = alloc::vec![b'x'; T::MaxNameLength::get() as usize];
+	BoundedVec::try_from(name).expect("name length is within bounds; qed")
+}
+
+/// Leave `who` a single mutation away from its rate limit in the current period.
+fn near_rate_limit<T: Config>(who: &T::AccountId) {
+	let now = frame_system::Pallet::<T>::block_number();
+	RateLimits::<T>::insert(who, (now, T::MaxInteractionsPerPeriod::get().saturating_sub(1)));
+}
+
+/// Fill the [`CounterHistory`] with entries of past blocks, so the next change of the counter
+/// drops the oldest one.
+fn fill_history<T: Config>() {
+	let max_history = T::MaxHistory::get();
+	frame_system::Pallet::<T>::set_block_number(max_history.into());
+	let history: alloc::vec::Vec<_> =
+		(0..max_history).map(|block| (block.into(), T::CounterValue::zero())).collect();
+	CounterHistory::<T>::put(
+		BoundedVec::try_from(history).expect("history has `MaxHistory` entries; qed"),
+	);
+}
+
+/// Fill the [`TopInteractors`] with accounts of a single interaction, followed by `caller` if
+/// given, leaving it the last place.
+///
+/// Without `caller`, its first interaction ranks it last on the now full leaderboard. Otherwise,
+/// it is searched among all entries and its next interaction moves it to the top.
+fn fill_leaderboard<T: Config>(caller: Option<&T::AccountId>) {
+	let mut leaderboard: alloc::vec::Vec<_> = (1..T::MaxLeaderboard::get())
+		.map(|index| (account::<T::AccountId>("interactor", index, 0), 1))
+		.collect();
+	leaderboard.extend(caller.map(|caller| (caller.clone(), 1)));
+	TopInteractors::<T>::put(BoundedVec::truncate_from(leaderboard));
+}
+
+/// The block after the current one.
+fn next_block<T: Config>() -> frame_system::pallet_prelude::BlockNumberFor<T> {
+	// The benchmarked call runs at block one or later, even if the setup runs at genesis.
+	frame_system::Pallet::<T>::block_number()
+		.max(One::one())
+		.saturating_add(One::one())
+}
+
+/// Insert a registry counter owned by `owner` with value `value` and the largest maximum.
+fn create_counter_for<T: Config>(owner: &T::AccountId, value: T::CounterValue) -> CounterId {
+	let id = NextCounterId::<T>::get();
+	let deposit = T::CounterDeposit::get();
+	fund::<T>(owner);
+	T::Currency::hold(&HoldReason::CounterDeposit.into(), owner, deposit)
+		.expect("owner was funded; qed");
+	Counters::<T>::insert(
+		id,
+		CounterInfo {
+			owner: owner.clone(),
+			name: full_name::<T>(),
+			value,
+			max: T::CounterValue::max_value(),
+			created_at: frame_system::Pallet::<T>::block_number(),
+			deposit,
+		},
+	);
+	NextCounterId::<T>::put(id + 1);
+	id
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_counter_value() {
+		fill_history::<T>();
+		let new_value = T::CounterMaxValue::get();
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, new_value);
+
+		assert_eq!(CounterValue::<T>::get(), Some(new_value));
+	}
+
+	// The caller has never interacted before, so a fresh `UserInteractions` entry is inserted.
+	// This is the worst case for `increment` and the one its weight is charged for.
+	#[benchmark]
+	fn increment() -> Result<(), BenchmarkError> {
+		fill_history::<T>();
+		let origin =
+			T::IncrementOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let caller = T::IncrementOrigin::ensure_origin(origin.clone())
+			.map_err(|_| BenchmarkError::Weightless)?;
+		near_rate_limit::<T>(&caller);
+		fund::<T>(&caller);
+		fill_leaderboard::<T>(None);
+		let max = T::CounterMaxValue::get();
+		CounterValue::<T>::put(max.saturating_sub(One::one()));
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, One::one());
+
+		assert_eq!(CounterValue::<T>::get(), Some(max));
+		assert_eq!(UserInteractions::<T>::get(&caller).map(|record| record.count), Some(1));
+
+		Ok(())
+	}
+
+	// The caller already has a `UserInteractions` entry which is read and overwritten.
+	#[benchmark]
+	fn increment_existing_interactor() -> Result<(), BenchmarkError> {
+		fill_history::<T>();
+		let origin =
+			T::IncrementOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let caller = T::IncrementOrigin::ensure_origin(origin.clone())
+			.map_err(|_| BenchmarkError::Weightless)?;
+		near_rate_limit::<T>(&caller);
+		fill_leaderboard::<T>(Some(&caller));
+		let max = T::CounterMaxValue::get();
+		CounterValue::<T>::put(max.saturating_sub(One::one()));
+		UserInteractions::<T>::insert(
+			&caller,
+			InteractionRecord { count: 1, last_block: frame_system::Pallet::<T>::block_number() },
+		);
+
+		#[extrinsic_call]
+		increment(origin as T::RuntimeOrigin, One::one());
+
+		assert_eq!(CounterValue::<T>::get(), Some(max));
+		assert_eq!(UserInteractions::<T>::get(&caller).map(|record| record.count), Some(2));
+
+		Ok(())
+	}
+
+	// Same as `increment`: a first-time caller is the worst case.
+	#[benchmark]
+	fn decrement() -> Result<(), BenchmarkError> {
+		fill_history::<T>();
+		let origin =
+			T::DecrementOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let caller = T::DecrementOrigin::ensure_origin(origin.clone())
+			.map_err(|_| BenchmarkError::Weightless)?;
+		near_rate_limit::<T>(&caller);
+		fund::<T>(&caller);
+		fill_leaderboard::<T>(None);
+		let max = T::CounterMaxValue::get();
+		CounterValue::<T>::put(max);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, One::one());
+
+		assert_eq!(CounterValue::<T>::get(), Some(max.saturating_sub(One::one())));
+		assert_eq!(UserInteractions::<T>::get(&caller).map(|record| record.count), Some(1));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn decrement_existing_interactor() -> Result<(), BenchmarkError> {
+		fill_history::<T>();
+		let origin =
+			T::DecrementOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let caller = T::DecrementOrigin::ensure_origin(origin.clone())
+			.map_err(|_| BenchmarkError::Weightless)?;
+		near_rate_limit::<T>(&caller);
+		fill_leaderboard::<T>(Some(&caller));
+		let max = T::CounterMaxValue::get();
+		CounterValue::<T>::put(max);
+		UserInteractions::<T>::insert(
+			&caller,
+			InteractionRecord { count: 1, last_block: frame_system::Pallet::<T>::block_number() },
+		);
+
+		#[extrinsic_call]
+		decrement(origin as T::RuntimeOrigin, One::one());
+
+		assert_eq!(CounterValue::<T>::get(), Some(max.saturating_sub(One::one())));
+		assert_eq!(UserInteractions::<T>::get(&caller).map(|record| record.count), Some(2));
+
+		Ok(())
+	}
+
+	// The counter is created, holding its deposit.
+	#[benchmark]
+	fn increment_own() {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), One::one());
+
+		assert_eq!(CountersByAccount::<T>::get(&caller), Some(One::one()));
+		assert_eq!(
+			T::Currency::balance_on_hold(&HoldReason::OwnCounterDeposit.into(), &caller),
+			T::CounterDeposit::get()
+		);
+	}
+
+	#[benchmark]
+	fn decrement_own() {
+		let caller: T::AccountId = whitelisted_caller();
+		let max = T::CounterMaxValue::get();
+		CountersByAccount::<T>::insert(&caller, max);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), One::one());
+
+		assert_eq!(CountersByAccount::<T>::get(&caller), Some(max.saturating_sub(One::one())));
+	}
+
+	#[benchmark]
+	fn reset_own() {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		T::Currency::hold(&HoldReason::OwnCounterDeposit.into(), &caller, T::CounterDeposit::get())
+			.expect("caller was funded; qed");
+		CountersByAccount::<T>::insert(&caller, T::CounterMaxValue::get());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert_eq!(CountersByAccount::<T>::get(&caller), None);
+		assert!(
+			T::Currency::balance_on_hold(&HoldReason::OwnCounterDeposit.into(), &caller).is_zero()
+		);
+	}
+
+	#[benchmark]
+	fn create_counter() {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let name = full_name::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), name, T::CounterValue::max_value(), Zero::zero());
+
+		assert_eq!(Counters::<T>::get(0).map(|info| info.owner), Some(caller));
+		assert_eq!(NextCounterId::<T>::get(), 1);
+	}
+
+	// Like `increment`, the signer has never interacted before. The validation of the transaction
+	// is included, as unsigned transactions pay no fee for it.
+	#[benchmark]
+	fn increment_unsigned() -> Result<(), BenchmarkError> {
+		fill_history::<T>();
+		fill_leaderboard::<T>(None);
+		let public =
+			<T::AuthorityId as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::generate_pair(
+				None,
+			);
+		let public: T::Public =
+			<T::AuthorityId as AppCrypto<T::Public, T::Signature>>::GenericPublic::from(public)
+				.into();
+		let who = public.clone().into_account();
+		// Admitted by an `EnsureAllowListed` increment origin, if any.
+		AllowList::<T>::insert(&who, ());
+		let payload = IncrementPayload {
+			public,
+			amount: One::one(),
+			nonce: 0,
+			genesis_hash: frame_system::Pallet::<T>::block_hash(
+				frame_system::pallet_prelude::BlockNumberFor::<T>::zero(),
+			),
+		};
+		let signature = SignedPayload::<T>::sign::<T::AuthorityId>(&payload)
+			.ok_or(BenchmarkError::Weightless)?;
+		let call = Call::<T>::increment_unsigned { payload, signature };
+
+		#[block]
+		{
+			Pallet::<T>::validate_unsigned(TransactionSource::External, &call)
+				.map_err(|_| BenchmarkError::Stop("invalid unsigned increment"))?;
+			<T as frame_system::Config>::RuntimeCall::from(call)
+				.dispatch(RawOrigin::None.into())
+				.map_err(|error| error.error)?;
+		}
+
+		assert_eq!(CounterValue::<T>::get(), Some(One::one()));
+		assert_eq!(UnsignedNonces::<T>::get(&who), 1);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn increment_counter() {
+		let caller: T::AccountId = whitelisted_caller();
+		let id = create_counter_for::<T>(&caller, Zero::zero());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), id, One::one());
+
+		assert_eq!(Counters::<T>::get(id).map(|info| info.value), Some(One::one()));
+	}
+
+	#[benchmark]
+	fn decrement_counter() {
+		let caller: T::AccountId = whitelisted_caller();
+		let id = create_counter_for::<T>(&caller, One::one());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), id, One::one());
+
+		assert_eq!(Counters::<T>::get(id).map(|info| info.value), Some(Zero::zero()));
+	}
+
+	#[benchmark]
+	fn transfer_counter_ownership() {
+		let caller: T::AccountId = whitelisted_caller();
+		let new_owner: T::AccountId = account("new_owner", 0, 0);
+		let id = create_counter_for::<T>(&caller, Zero::zero());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), id, T::Lookup::unlookup(new_owner.clone()));
+
+		assert_eq!(PendingCounterOwners::<T>::get(id), Some(new_owner));
+	}
+
+	#[benchmark]
+	fn accept_counter_ownership() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let id = create_counter_for::<T>(&owner, Zero::zero());
+		PendingCounterOwners::<T>::insert(id, &caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), id);
+
+		assert_eq!(Counters::<T>::get(id).map(|info| info.owner), Some(caller));
+		assert_eq!(PendingCounterOwners::<T>::get(id), None);
+	}
+
+	#[benchmark]
+	fn destroy_counter() {
+		let caller: T::AccountId = whitelisted_caller();
+		let id = create_counter_for::<T>(&caller, Zero::zero());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), id);
+
+		assert!(Counters::<T>::get(id).is_none());
+	}
+
+	#[benchmark]
+	fn clear_interactions() {
+		let caller: T::AccountId = whitelisted_caller();
+		// The rate period of the caller's latest mutation is over.
+		frame_system::Pallet::<T>::set_block_number(T::RatePeriod::get());
+		RateLimits::<T>::insert(
+			&caller,
+			(frame_system::pallet_prelude::BlockNumberFor::<T>::zero(), 1),
+		);
+		fund::<T>(&caller);
+		fill_leaderboard::<T>(Some(&caller));
+		T::Currency::hold(
+			&HoldReason::InteractionDeposit.into(),
+			&caller,
+			T::InteractionDeposit::get(),
+		)
+		.expect("caller was funded; qed");
+		UserInteractions::<T>::insert(
+			&caller,
+			InteractionRecord { count: 1, last_block: frame_system::Pallet::<T>::block_number() },
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert_eq!(UserInteractions::<T>::get(&caller), None);
+		assert_eq!(RateLimits::<T>::get(&caller), None);
+	}
+
+	#[benchmark]
+	fn add_to_allow_list() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let who: T::AccountId = account("member", 0, 0);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, T::Lookup::unlookup(who.clone()));
+
+		assert!(AllowList::<T>::contains_key(&who));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_from_allow_list() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let who: T::AccountId = account("member", 0, 0);
+		AllowList::<T>::insert(&who, ());
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, T::Lookup::unlookup(who.clone()));
+
+		assert!(!AllowList::<T>::contains_key(&who));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_max_value() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let max = T::CounterMaxValue::get();
+		CounterValue::<T>::put(max);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, Some(max.saturating_add(One::one())));
+
+		assert_eq!(MaxValueOverride::<T>::get(), Some(max.saturating_add(One::one())));
+
+		Ok(())
+	}
+
+	// `n` increments by a first-time caller, with the counter overridden to leave room for all of
+	// them.
+	#[benchmark]
+	fn apply_operations(n: Linear<1, { T::MaxOpsPerCall::get() }>) -> Result<(), BenchmarkError> {
+		fill_history::<T>();
+		let origin =
+			T::IncrementOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let caller = T::IncrementOrigin::ensure_origin(origin.clone())
+			.map_err(|_| BenchmarkError::Weightless)?;
+		near_rate_limit::<T>(&caller);
+		fund::<T>(&caller);
+		fill_leaderboard::<T>(None);
+		MaxValueOverride::<T>::put(T::CounterValue::max_value());
+		CounterValue::<T>::put(T::CounterValue::zero());
+		let operations: BoundedVec<_, _> =
+			alloc::vec![CounterOp::Increment(One::one()); n as usize]
+				.try_into()
+				.expect("`n` does not exceed `MaxOpsPerCall`; qed");
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, operations);
+
+		assert_eq!(UserInteractions::<T>::get(&caller).map(|record| record.count), Some(1));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reset_counter() {
+		fill_history::<T>();
+		CounterValue::<T>::put(T::CounterMaxValue::get());
+
+		#[extrinsic_call]
+		_(RawOrigin::Root);
+
+		assert_eq!(CounterValue::<T>::get(), None);
+	}
+
+	#[benchmark]
+	fn schedule_counter_value() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let at = next_block::<T>();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, at, T::CounterMaxValue::get());
+
+		assert_eq!(T::Scheduler::next_dispatch_time(Pallet::<T>::counter_value_task(at)), Ok(at));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_scheduled_counter_value() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let at = next_block::<T>();
+		Pallet::<T>::schedule_counter_value(origin.clone(), at, T::CounterMaxValue::get())?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, at);
+
+		assert!(T::Scheduler::next_dispatch_time(Pallet::<T>::counter_value_task(at)).is_err());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn schedule_reset() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let at = next_block::<T>();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, at, Some(One::one()));
+
+		assert_eq!(T::Scheduler::next_dispatch_time(Pallet::<T>::reset_task()), Ok(at));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_scheduled_reset() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		Pallet::<T>::schedule_reset(origin.clone(), next_block::<T>(), Some(One::one()))?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin);
+
+		assert!(T::Scheduler::next_dispatch_time(Pallet::<T>::reset_task()).is_err());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn on_initialize_reset() -> Result<(), BenchmarkError> {
+		let period = T::ResetPeriod::get().ok_or(BenchmarkError::Weightless)?;
+		fill_history::<T>();
+		// A reset block after all the entries of the history.
+		let n = period.saturating_mul(T::MaxHistory::get().saturating_add(1).into());
+		frame_system::Pallet::<T>::set_block_number(n);
+		CounterValue::<T>::put(T::CounterMaxValue::get());
+
+		#[block]
+		{
+			Pallet::<T>::on_initialize(n);
+		}
+
+		assert_eq!(CounterValue::<T>::get(), None);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_authorized_key() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let who: T::AccountId = account("key", 0, 0);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, T::Lookup::unlookup(who.clone()));
+
+		assert!(AuthorizedKeys::<T>::contains_key(&who));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_authorized_key() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let who: T::AccountId = account("key", 0, 0);
+		AuthorizedKeys::<T>::insert(&who, ());
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, T::Lookup::unlookup(who.clone()));
+
+		assert!(!AuthorizedKeys::<T>::contains_key(&who));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn submit_external_value() {
+		fill_history::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+		AuthorizedKeys::<T>::insert(&caller, ());
+		let new_value = T::CounterMaxValue::get();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), new_value);
+
+		assert_eq!(CounterValue::<T>::get(), Some(new_value));
+	}
+
+	#[benchmark]
+	fn increment_from_remote() -> Result<(), BenchmarkError> {
+		let origin =
+			T::XcmOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let para_id =
+			T::XcmOrigin::ensure_origin(origin.clone()).map_err(|_| BenchmarkError::Weightless)?;
+		fill_history::<T>();
+		let last_block = frame_system::Pallet::<T>::block_number();
+		ParaInteractions::<T>::insert(para_id, InteractionRecord { count: 1, last_block });
+		let amount = T::CounterValue::one();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, para_id, amount);
+
+		assert_eq!(CounterValue::<T>::get(), Some(amount));
+		assert_eq!(ParaInteractions::<T>::get(para_id).map(|record| record.count), Some(2));
+
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,35 @@
+//! Keys signing the transactions of the off-chain worker.
+//!
+//! The off-chain worker signs [`submit_external_value`] with any key of type [`KEY_TYPE`] in the
+//! node's keystore. The account of that key must be on the [`AuthorizedKeys`] for the transaction
+//! to succeed.
+//!
+//! A key is inserted with the `author_insertKey` RPC, for instance:
+//!
+//! ```text
+//! author_insertKey("cntr", "<secret URI>", "<public key>")
+//! ```
+//!
+//! [`submit_external_value`]: crate::Pallet::submit_external_value
+//! [`AuthorizedKeys`]: crate::AuthorizedKeys
+
+use sp_core::sr25519::{Public as Sr25519Public, Signature as Sr25519Signature};
+use sp_runtime::{
+	app_crypto::{app_crypto, sr25519},
+	KeyTypeId, MultiSignature, MultiSigner,
+};
+
+/// The key type of the keys signing the off-chain worker transactions.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"cntr");
+
+app_crypto!(sr25519, KEY_TYPE);
+
+/// The [`AppCrypto`](frame_system::offchain::AppCrypto) of the off-chain worker, for runtimes
+/// signing transactions with a [`MultiSignature`].
+pub struct AuthorityId;
+
+impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for AuthorityId {
+	type RuntimeAppPublic = Public;
+	type GenericPublic = Sr25519Public;
+	type GenericSignature = Sr25519Signature;
+}
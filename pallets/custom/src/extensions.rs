@@ -0,0 +1,147 @@
+//! Transaction extensions of the custom pallet.
+
+use crate::{Call, Config, Pallet};
+use codec::{Decode, DecodeWithMemTracking, Encode};
+use core::marker::PhantomData;
+use frame_support::{
+	pallet_prelude::TransactionSource,
+	traits::{Get, IsSubType},
+	weights::Weight,
+	DefaultNoBound,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	impl_tx_ext_default,
+	traits::{CheckedAdd, CheckedSub, DispatchInfoOf, TransactionExtension, Zero},
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+};
+
+type RuntimeCallOf<T> = <T as frame_system::Config>::RuntimeCall;
+
+/// The [`InvalidTransaction::Custom`] codes of the transactions rejected by this pallet.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum ValidityError {
+	/// The call would take the global counter above its maximum.
+	CounterValueExceedsMax = 0,
+	/// The call would take the global counter below `CounterMinValue`.
+	CounterValueBelowMin = 1,
+	/// The call changes the global counter by a negative amount.
+	NegativeAmount = 2,
+	/// The signer of an unsigned increment has used up its mutations of the current period.
+	RateLimited = 3,
+	/// The call changes the global counter by a zero amount.
+	ZeroAmount = 4,
+	/// The signer of an unsigned increment already has an interactions record.
+	AlreadyInteracted = 5,
+}
+
+impl From<ValidityError> for TransactionValidityError {
+	fn from(error: ValidityError) -> Self {
+		InvalidTransaction::Custom(error as u8).into()
+	}
+}
+
+/// Reject [`Pallet::increment`] and [`Pallet::decrement`] calls that would take the global
+/// counter out of its bounds, so they are neither kept in the transaction pool nor charged for.
+///
+/// The calls are checked against the current value of the counter, both when entering the pool
+/// and when included in a block. A transaction valid in the pool may still fail if other
+/// transactions change the counter before it.
+#[derive(Encode, Decode, DecodeWithMemTracking, DefaultNoBound, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckCounterBounds<T>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> core::fmt::Debug for CheckCounterBounds<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "CheckCounterBounds")
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut core::fmt::Formatter) -> core::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config + Send + Sync> CheckCounterBounds<T> {
+	/// Create a new `CheckCounterBounds` extension.
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+
+	/// Check that `call`, if it increments or decrements the global counter, keeps it within
+	/// its bounds.
+	fn check(call: &RuntimeCallOf<T>) -> Result<(), ValidityError>
+	where
+		RuntimeCallOf<T>: IsSubType<Call<T>>,
+	{
+		let counter_value = Pallet::<T>::counter_value();
+		match call.is_sub_type() {
+			Some(Call::increment { amount_to_increment: amount }) => {
+				if *amount < Zero::zero() {
+					return Err(ValidityError::NegativeAmount);
+				}
+				counter_value
+					.checked_add(amount)
+					.filter(|new_value| *new_value <= Pallet::<T>::max_value())
+					.map(|_| ())
+					.ok_or(ValidityError::CounterValueExceedsMax)
+			},
+			Some(Call::decrement { amount_to_decrement: amount }) => {
+				if *amount < Zero::zero() {
+					return Err(ValidityError::NegativeAmount);
+				}
+				counter_value
+					.checked_sub(amount)
+					.filter(|new_value| *new_value >= Pallet::<T>::min_value())
+					.map(|_| ())
+					.ok_or(ValidityError::CounterValueBelowMin)
+			},
+			_ => Ok(()),
+		}
+	}
+
+	/// Whether `call` is checked by this extension.
+	fn is_checked(call: &RuntimeCallOf<T>) -> bool
+	where
+		RuntimeCallOf<T>: IsSubType<Call<T>>,
+	{
+		matches!(call.is_sub_type(), Some(Call::increment { .. } | Call::decrement { .. }))
+	}
+}
+
+impl<T: Config + Send + Sync> TransactionExtension<RuntimeCallOf<T>> for CheckCounterBounds<T>
+where
+	RuntimeCallOf<T>: IsSubType<Call<T>>,
+{
+	const IDENTIFIER: &'static str = "CheckCounterBounds";
+	type Implicit = ();
+	type Val = ();
+	type Pre = ();
+
+	fn weight(&self, call: &RuntimeCallOf<T>) -> Weight {
+		// Reads `CounterValue` and `MaxValueOverride`.
+		if Self::is_checked(call) {
+			T::DbWeight::get().reads(2)
+		} else {
+			Weight::zero()
+		}
+	}
+
+	fn validate(
+		&self,
+		origin: T::RuntimeOrigin,
+		call: &RuntimeCallOf<T>,
+		_info: &DispatchInfoOf<RuntimeCallOf<T>>,
+		_len: usize,
+		_self_implicit: Self::Implicit,
+		_inherited_implication: &impl Encode,
+		_source: TransactionSource,
+	) -> sp_runtime::traits::ValidateResult<Self::Val, RuntimeCallOf<T>> {
+		Self::check(call)?;
+		Ok((Default::default(), (), origin))
+	}
+
+	impl_tx_ext_default!(RuntimeCallOf<T>; prepare);
+}
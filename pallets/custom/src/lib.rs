@@ -0,0 +1,2229 @@
+//! # Custom Pallet
+//!
+//! A simple counter pallet built for the "Build a Custom Pallet" exercise.
+//!
+//! ## Overview
+//!
+//! The pallet keeps a single global counter in [`CounterValue`] that can be incremented by
+//! [`Config::IncrementOrigin`] and decremented by [`Config::DecrementOrigin`], bounded by
+//! [`Config::CounterMaxValue`]. Root may overwrite the counter directly through
+//! [`Pallet::set_counter_value`]. Every successful increment or decrement is recorded per account
+//! in [`UserInteractions`]. Each account may change the global counter at most
+//! [`Config::MaxInteractionsPerPeriod`] times per [`Config::RatePeriod`].
+//!
+//! All counter values, and the amounts they are changed by, are of the runtime's choice of
+//! [`Config::CounterValue`], such as `u32`, `u64` or `u128`. No counter goes below
+//! [`Config::CounterMinValue`]. Choosing a signed type such as `i64` with a negative minimum
+//! enables the signed mode, where counters can track deltas that legitimately go negative.
+//!
+//! The maximum of the global counter can be changed without a runtime upgrade:
+//! [`Config::AdminOrigin`] may set a [`MaxValueOverride`] through [`Pallet::set_max_value`], as
+//! long as it is neither below the current value of the counter nor below
+//! [`Config::CounterMinValue`].
+//!
+//! Accounts without funds to pay transaction fees, such as new users, may still make their first
+//! increment of the global counter through [`Pallet::increment_unsigned`]: they sign an
+//! [`IncrementPayload`] with their account key and anyone may submit it as an unsigned
+//! transaction. At most [`Config::MaxUnsignedPerBlock`] of them are included in a block, and
+//! accounts with an interactions record must pay for their increments. Payloads are ordered and
+//! protected from replay by the per-account [`UnsignedNonces`] and by the genesis hash of the
+//! chain they carry. Only increments that would currently succeed are accepted into the
+//! transaction pool, and one failing at inclusion still consumes its nonce. Their priority and
+//! longevity in the transaction pool are set by [`Config::UnsignedPriority`] and
+//! [`Config::UnsignedLongevity`].
+//!
+//! Several changes of the global counter can be submitted in a single transaction through
+//! [`Pallet::apply_operations`]. The batch is applied atomically and counts as a single
+//! interaction, both in [`UserInteractions`] and against the rate limit.
+//!
+//! Changes of the global counter can also be delayed: [`Config::AdminOrigin`] may have the
+//! [`Config::Scheduler`] dispatch [`Pallet::set_counter_value`] or [`Pallet::reset_counter`] as
+//! Root at a future block. Resets can repeat every given number of blocks, so daily counters do
+//! not need an off-chain bot sending root calls.
+//!
+//! A runtime may also have the counter roll over on its own: when [`Config::ResetPeriod`] is set,
+//! the counter is reset at the start of every period, or era. With
+//! [`Config::ResetInteractions`], the interactions counted in [`UserInteractions`] restart every
+//! era as well, and the leaderboard is cleared. Records of past eras are not removed, they are
+//! treated as empty and overwritten by the next interaction of their account, so the reset takes a
+//! bounded weight.
+//!
+//! The latest values of the counter are kept in [`CounterHistory`], so its trajectory can be
+//! queried through [`Pallet::counter_value_at`] without replaying events.
+//!
+//! The most active accounts are ranked on the [`TopInteractors`] leaderboard, kept sorted as
+//! [`UserInteractions`] changes so it can be read without iterating over all records.
+//!
+//! The global counter can mirror an external metric: every [`Config::OffchainInterval`] blocks,
+//! the off-chain worker fetches a value over HTTP and submits it through
+//! [`Pallet::submit_external_value`], signed with a [`crypto`] key of the node. Only the accounts
+//! that [`Config::AdminOrigin`] put on the [`AuthorizedKeys`] may submit values.
+//!
+//! Other chains may increment the global counter as well: an XCM `Transact` from a sibling
+//! parachain, converted into [`Config::XcmOrigin`], dispatches [`Pallet::increment_from_remote`].
+//! Remote increments are recorded per parachain in [`ParaInteractions`], instead of
+//! [`UserInteractions`], and are neither rate limited nor charged a deposit.
+//!
+//! Besides the global counter, every account may keep a private counter in
+//! [`CountersByAccount`], bounded by [`Config::CounterMaxValue`].
+//!
+//! Finally, the pallet hosts a registry of independent named [`Counters`]. Each registry counter
+//! is identified by a [`CounterId`], has its own maximum and can only be changed by its owner.
+//! Ownership changes hands in two steps: the owner offers the counter through
+//! [`Pallet::transfer_counter_ownership`], and the new owner takes it, along with its deposit,
+//! through [`Pallet::accept_counter_ownership`].
+//!
+//! ## Genesis
+//!
+//! A chain spec may set the initial [`CounterValue`], pre-populate [`UserInteractions`] and seed
+//! the [`AllowList`] and the [`AuthorizedKeys`] through [`GenesisConfig`]. The initial value must
+//! not exceed [`Config::CounterMaxValue`].
+//!
+//! ## Deposits
+//!
+//! Storage entries created on behalf of an account are paid for with a held deposit. Creating a
+//! registry counter holds [`Config::CounterDeposit`] from its owner until the counter is
+//! destroyed, and so does the private counter of an account until it is reset through
+//! [`Pallet::reset_own`]. The first interaction of an account holds [`Config::InteractionDeposit`]
+//! until the record is removed through [`Pallet::clear_interactions`], along with its
+//! [`RateLimits`] entry once the current rate period is over. Records created by
+//! [`Pallet::increment_unsigned`] hold no deposit, as their accounts may have no funds.
+//!
+//! ## Off-chain Worker
+//!
+//! The URL of the external source is read from the node's persistent off-chain local storage
+//! under [`EXTERNAL_VALUE_URL_KEY`], so every node operator can choose their own, for instance
+//! with the `offchain_localStorageSet` RPC. The source must answer a `GET` request with a body
+//! holding the value as a decimal integer. Nodes without a URL or without a signing key on the
+//! [`AuthorizedKeys`] skip the sync, and values equal to the current one or outside the bounds of
+//! the counter are not submitted.
+//!
+//! ## Transaction Extension
+//!
+//! Runtimes may add [`CheckCounterBounds`] to their transaction extensions to reject
+//! [`Pallet::increment`] and [`Pallet::decrement`] calls that would take the global counter out of
+//! its bounds before they enter the transaction pool, instead of charging fees for calls bound to
+//! fail.
+//!
+//! ## Access Control
+//!
+//! Runtimes choose who may change the global counter through [`Config::IncrementOrigin`] and
+//! [`Config::DecrementOrigin`]. Besides the usual origins, [`EnsureAllowListed`] admits only the
+//! signed accounts that [`Config::AdminOrigin`] put on the [`AllowList`].
+//!
+//! ## Other Pallets
+//!
+//! Other pallets may read and change the global counter without depending on this pallet's
+//! `Config`, through a `Config` associated type bound by [`traits::CounterInspect`] or
+//! [`traits::CounterMutate`]. Runtimes set it to [`Pallet`], and mocks to `()`, which ignores
+//! every change.
+//!
+//! ## Dispatchable Functions
+//!
+//! - [`Pallet::set_counter_value`]: Set the counter to a specific value. Root only.
+//! - [`Pallet::increment`]: Increase the counter by a given amount.
+//! - [`Pallet::decrement`]: Decrease the counter by a given amount.
+//! - [`Pallet::increment_unsigned`]: Increase the counter by a given amount without paying fees.
+//! - [`Pallet::increment_from_remote`]: Increase the counter by a given amount from a sibling
+//!   parachain. `XcmOrigin` only.
+//! - [`Pallet::increment_own`]: Increase the caller's own counter by a given amount.
+//! - [`Pallet::decrement_own`]: Decrease the caller's own counter by a given amount.
+//! - [`Pallet::reset_own`]: Clear the caller's own counter.
+//! - [`Pallet::create_counter`]: Create a named counter in the registry.
+//! - [`Pallet::increment_counter`]: Increase a registry counter by a given amount.
+//! - [`Pallet::decrement_counter`]: Decrease a registry counter by a given amount.
+//! - [`Pallet::transfer_counter_ownership`]: Offer a registry counter to another account.
+//! - [`Pallet::accept_counter_ownership`]: Take over a registry counter offered to the caller.
+//! - [`Pallet::destroy_counter`]: Remove a registry counter.
+//! - [`Pallet::clear_interactions`]: Remove the caller's interactions record and rate limit.
+//! - [`Pallet::add_to_allow_list`]: Approve an account. Admin only.
+//! - [`Pallet::remove_from_allow_list`]: Revoke an account's approval. Admin only.
+//! - [`Pallet::set_max_value`]: Override the maximum of the global counter. Admin only.
+//! - [`Pallet::apply_operations`]: Apply a batch of [`CounterOp`]s to the global counter at once.
+//! - [`Pallet::reset_counter`]: Reset the counter. Root only.
+//! - [`Pallet::schedule_counter_value`]: Set the counter at a future block. Admin only.
+//! - [`Pallet::cancel_scheduled_counter_value`]: Cancel a scheduled counter value. Admin only.
+//! - [`Pallet::schedule_reset`]: Reset the counter at a future block, optionally periodically.
+//!   Admin only.
+//! - [`Pallet::cancel_scheduled_reset`]: Cancel the scheduled reset. Admin only.
+//! - [`Pallet::add_authorized_key`]: Allow an account to submit external values. Admin only.
+//! - [`Pallet::remove_authorized_key`]: Revoke an account's right to submit external values. Admin
+//!   only.
+//! - [`Pallet::submit_external_value`]: Set the counter to the value of the external source.
+//!   Authorized keys only.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod crypto;
+pub mod extensions;
+pub mod migrations;
+pub mod traits;
+pub mod types;
+pub mod weights;
+pub use extensions::CheckCounterBounds;
+pub use polkadot_parachain_primitives::primitives::Id as ParaId;
+pub use types::*;
+pub use weights::WeightInfo;
+
+use frame_support::traits::EnsureOrigin;
+use frame_system::RawOrigin;
+
+/// The key of the off-chain local storage entry holding the URL the off-chain worker fetches the
+/// external value from.
+pub const EXTERNAL_VALUE_URL_KEY: &[u8] = b"pallet-custom::external-value-url";
+
+/// Parse the body of an answer of the external source: a decimal integer, optionally surrounded
+/// by whitespace.
+fn parse_external_value<V: core::str::FromStr>(body: &[u8]) -> Option<V> {
+	core::str::from_utf8(body).ok()?.trim().parse().ok()
+}
+
+/// The log target of this pallet.
+const LOG_TARGET: &str = "runtime::custom";
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::{
+		extensions::ValidityError, parse_external_value, CounterId, CounterInfo, CounterOp,
+		IncrementPayload, InteractionRecord, ParaId, WeightInfo, EXTERNAL_VALUE_URL_KEY,
+		LOG_TARGET,
+	};
+	use alloc::vec::Vec;
+	use codec::{Codec, DecodeWithMemTracking, Encode};
+	use core::str::FromStr;
+	use frame_support::{
+		pallet_prelude::*,
+		storage::with_storage_layer,
+		traits::{
+			fungible::{Inspect, Mutate, MutateHold},
+			schedule::{
+				v3::{Named as ScheduleNamed, TaskName},
+				DispatchTime, HARD_DEADLINE,
+			},
+			tokens::Precision,
+			QueryPreimage, StorePreimage,
+		},
+		Hashable,
+	};
+	use frame_system::{
+		offchain::{
+			AppCrypto, CreateSignedTransaction, SendSignedTransaction, Signer, SigningTypes,
+		},
+		pallet_prelude::*,
+	};
+	use sp_runtime::{
+		offchain::{http, Duration, StorageKind},
+		traits::{
+			Bounded, CheckedAdd, CheckedSub, IdentifyAccount, MaybeSerializeDeserialize, Member,
+			One, Saturating, StaticLookup, Verify, Zero,
+		},
+		transaction_validity::{TransactionLongevity, TransactionPriority},
+		RuntimeAppPublic,
+	};
+
+	type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+
+	/// The balance type of the currency used for storage deposits.
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// How long the off-chain worker waits for the external source to answer, in milliseconds.
+	const FETCH_TIMEOUT_MS: u64 = 2_000;
+
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// Configuration trait of this pallet.
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config<RuntimeEvent: From<Event<Self>>, RuntimeCall: From<Call<Self>>>
+		+ CreateSignedTransaction<Call<Self>>
+		+ SigningTypes<
+			Public: DecodeWithMemTracking,
+			Signature: Verify<Signer = Self::Public> + DecodeWithMemTracking,
+		>
+	{
+		/// The type of the counter values and of the amounts they are changed by: any signed or
+		/// unsigned integer such as `u32`, `u64`, `u128` or `i64`.
+		///
+		/// A signed type together with a negative [`Config::CounterMinValue`] lets counters go
+		/// below zero.
+		type CounterValue: Parameter
+			+ Member
+			+ Codec
+			+ Ord
+			+ Zero
+			+ One
+			+ Bounded
+			+ CheckedAdd
+			+ CheckedSub
+			+ Saturating
+			+ TryFrom<i64>
+			+ FromStr
+			+ Copy
+			+ MaybeSerializeDeserialize
+			+ MaxEncodedLen
+			+ TypeInfo;
+
+		/// The maximum value the counter can hold, unless overridden by [`MaxValueOverride`].
+		#[pallet::constant]
+		type CounterMaxValue: Get<Self::CounterValue>;
+
+		/// The minimum value every counter can hold. Must be representable by
+		/// [`Config::CounterValue`], so it can only be negative for signed types.
+		#[pallet::constant]
+		type CounterMinValue: Get<i64>;
+
+		/// The maximum number of operations in a single [`Pallet::apply_operations`] call.
+		#[pallet::constant]
+		type MaxOpsPerCall: Get<u32>;
+
+		/// The maximum number of entries kept in the [`CounterHistory`].
+		#[pallet::constant]
+		type MaxHistory: Get<u32>;
+
+		/// The maximum number of accounts on the [`TopInteractors`] leaderboard.
+		#[pallet::constant]
+		type MaxLeaderboard: Get<u32>;
+
+		/// The maximum length of a registry counter name.
+		#[pallet::constant]
+		type MaxNameLength: Get<u32>;
+
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
+
+		/// The currency used to hold storage deposits.
+		type Currency: Mutate<Self::AccountId>
+			+ MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+		/// The deposit held from the owner of a registry counter, or of a private counter, while
+		/// it exists.
+		#[pallet::constant]
+		type CounterDeposit: Get<BalanceOf<Self>>;
+
+		/// The deposit held from an account while it has a [`UserInteractions`] record.
+		#[pallet::constant]
+		type InteractionDeposit: Get<BalanceOf<Self>>;
+
+		/// The maximum number of global counter mutations an account may perform per
+		/// [`Config::RatePeriod`].
+		#[pallet::constant]
+		type MaxInteractionsPerPeriod: Get<u32>;
+
+		/// The length, in blocks, of a rate limiting period. A zero period disables rate
+		/// limiting.
+		#[pallet::constant]
+		type RatePeriod: Get<BlockNumberFor<Self>>;
+
+		/// The number of blocks after which the global counter is reset, or `None` to never reset
+		/// it automatically. Must not be zero.
+		#[pallet::constant]
+		type ResetPeriod: Get<Option<BlockNumberFor<Self>>>;
+
+		/// Whether the interactions counted in [`UserInteractions`] restart every
+		/// [`Config::ResetPeriod`] along with the counter.
+		#[pallet::constant]
+		type ResetInteractions: Get<bool>;
+
+		/// The origin allowed to increment the global counter.
+		type IncrementOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+		/// The origin allowed to decrement the global counter.
+		type DecrementOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+		/// The origin allowed to manage the [`AllowList`], the [`MaxValueOverride`] and scheduled
+		/// counter changes.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The caller origin, overarching type of all pallets origins.
+		type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>;
+
+		/// The scheduler dispatching delayed counter changes.
+		type Scheduler: ScheduleNamed<
+			BlockNumberFor<Self>,
+			<Self as frame_system::Config>::RuntimeCall,
+			Self::PalletsOrigin,
+			Hasher = Self::Hashing,
+		>;
+
+		/// The preimage provider used to hand scheduled calls to the [`Config::Scheduler`].
+		type Preimages: QueryPreimage<H = Self::Hashing> + StorePreimage;
+
+		/// The keys the off-chain worker signs [`Pallet::submit_external_value`] with, usually
+		/// [`crypto::AuthorityId`](crate::crypto::AuthorityId).
+		type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+
+		/// The number of blocks between two syncs of the global counter with the external
+		/// source by the off-chain worker. A zero interval disables the off-chain worker.
+		#[pallet::constant]
+		type OffchainInterval: Get<BlockNumberFor<Self>>;
+
+		/// The priority of [`Pallet::increment_unsigned`] transactions in the transaction pool.
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+
+		/// The number of blocks [`Pallet::increment_unsigned`] transactions stay valid for in the
+		/// transaction pool.
+		#[pallet::constant]
+		type UnsignedLongevity: Get<TransactionLongevity>;
+
+		/// The maximum number of [`Pallet::increment_unsigned`] transactions included in a block.
+		#[pallet::constant]
+		type MaxUnsignedPerBlock: Get<u32>;
+
+		/// The origin of the XCM messages allowed to call [`Pallet::increment_from_remote`],
+		/// yielding the sending parachain. Usually a sibling parachain origin, as converted from
+		/// an XCM `Transact` by `SiblingParachainAsNative`.
+		type XcmOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = ParaId>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// A reason for the pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// The funds are held as a deposit for a registry counter.
+		#[codec(index = 0)]
+		CounterDeposit,
+		/// The funds are held as a deposit for a user interactions record.
+		#[codec(index = 1)]
+		InteractionDeposit,
+		/// The funds are held as a deposit for the private counter of an account.
+		#[codec(index = 2)]
+		OwnCounterDeposit,
+	}
+
+	/// The current value of the counter.
+	#[pallet::storage]
+	pub type CounterValue<T: Config> = StorageValue<_, T::CounterValue>;
+
+	/// The latest values of the global counter, oldest first, along with the block each was
+	/// set in.
+	///
+	/// Holds at most one entry per block, with the value at the end of the block. Once
+	/// `MaxHistory` entries are recorded, the oldest one is dropped for every new one.
+	#[pallet::storage]
+	pub type CounterHistory<T: Config> = StorageValue<
+		_,
+		BoundedVec<(BlockNumberFor<T>, T::CounterValue), T::MaxHistory>,
+		ValueQuery,
+	>;
+
+	/// The accounts with the most interactions, most active first, along with their number of
+	/// interactions in [`UserInteractions`].
+	///
+	/// Accounts with as many interactions are ranked by who reached that number first. Cleared
+	/// records leave the leaderboard without making room for the accounts that did not fit on it,
+	/// so it may hold fewer than `MaxLeaderboard` entries until they interact again.
+	#[pallet::storage]
+	pub type TopInteractors<T: Config> =
+		StorageValue<_, BoundedVec<(T::AccountId, u32), T::MaxLeaderboard>, ValueQuery>;
+
+	/// The maximum value of the global counter set by the `AdminOrigin`, replacing
+	/// [`Config::CounterMaxValue`] while present.
+	#[pallet::storage]
+	pub type MaxValueOverride<T: Config> = StorageValue<_, T::CounterValue>;
+
+	/// How many times each account has incremented or decremented the counter, and when it last
+	/// did so.
+	#[pallet::storage]
+	pub type UserInteractions<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, InteractionRecord<BlockNumberFor<T>>>;
+
+	/// Private counters kept by individual accounts, independent of the global [`CounterValue`].
+	#[pallet::storage]
+	pub type CountersByAccount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::CounterValue>;
+
+	/// The start of the current rate limiting period of each account and the number of global
+	/// counter mutations it performed within it.
+	#[pallet::storage]
+	pub type RateLimits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (BlockNumberFor<T>, u32)>;
+
+	/// Accounts approved by the `AdminOrigin`.
+	///
+	/// Only consulted when a runtime uses [`EnsureAllowListed`](crate::EnsureAllowListed) as
+	/// one of its origins.
+	#[pallet::storage]
+	pub type AllowList<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+	/// How many times each sibling parachain has incremented the counter through
+	/// [`Pallet::increment_from_remote`], and when it last did so.
+	#[pallet::storage]
+	pub type ParaInteractions<T: Config> =
+		StorageMap<_, Twox64Concat, ParaId, InteractionRecord<BlockNumberFor<T>>>;
+
+	/// The nonce the next [`IncrementPayload`] of each account must carry.
+	#[pallet::storage]
+	pub type UnsignedNonces<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The last block including [`Pallet::increment_unsigned`] transactions and their number in
+	/// it.
+	#[pallet::storage]
+	pub type UnsignedIncrementsInBlock<T: Config> = StorageValue<_, (BlockNumberFor<T>, u32)>;
+
+	/// Accounts allowed to submit the value of the external source by the `AdminOrigin`.
+	#[pallet::storage]
+	pub type AuthorizedKeys<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+	/// The identifier the next registry counter will be created with.
+	#[pallet::storage]
+	pub type NextCounterId<T> = StorageValue<_, CounterId, ValueQuery>;
+
+	/// The registry of named counters.
+	#[pallet::storage]
+	pub type Counters<T: Config> = StorageMap<_, Twox64Concat, CounterId, CounterInfo<T>>;
+
+	/// The accounts registry counters are offered to, until they accept the ownership.
+	#[pallet::storage]
+	pub type PendingCounterOwners<T: Config> = StorageMap<_, Twox64Concat, CounterId, T::AccountId>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// The initial value of the global counter, if any. Must lie within `CounterMinValue` and
+		/// `CounterMaxValue`.
+		pub counter_value: Option<T::CounterValue>,
+		/// Accounts starting with a number of interactions with the global counter.
+		///
+		/// No deposit is held for these records.
+		pub interactions: Vec<(T::AccountId, u32)>,
+		/// Accounts starting on the allow list.
+		pub allow_list: Vec<T::AccountId>,
+		/// Accounts starting authorized to submit external values.
+		pub authorized_keys: Vec<T::AccountId>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			if let Some(counter_value) = self.counter_value {
+				assert!(
+					counter_value <= T::CounterMaxValue::get(),
+					"the initial counter value exceeds `CounterMaxValue`"
+				);
+				assert!(
+					counter_value >= Pallet::<T>::min_value(),
+					"the initial counter value is below `CounterMinValue`"
+				);
+				Pallet::<T>::put_counter_value(counter_value);
+			}
+
+			for (who, count) in &self.interactions {
+				assert!(
+					!UserInteractions::<T>::contains_key(who),
+					"duplicate account in the initial interactions"
+				);
+				UserInteractions::<T>::insert(
+					who,
+					InteractionRecord { count: *count, last_block: Zero::zero() },
+				);
+				Pallet::<T>::update_leaderboard(who, 0, *count);
+			}
+
+			for who in &self.allow_list {
+				AllowList::<T>::insert(who, ());
+			}
+
+			for who in &self.authorized_keys {
+				AuthorizedKeys::<T>::insert(who, ());
+			}
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The counter value has been set to a new value by Root.
+		CounterValueSet {
+			/// The new value set.
+			counter_value: T::CounterValue,
+		},
+		/// A user has successfully incremented the counter.
+		CounterIncremented {
+			/// The new value set.
+			counter_value: T::CounterValue,
+			/// The account who incremented the counter.
+			who: T::AccountId,
+			/// The amount by which the counter was incremented.
+			incremented_amount: T::CounterValue,
+		},
+		/// A user has successfully decremented the counter.
+		CounterDecremented {
+			/// The new value set.
+			counter_value: T::CounterValue,
+			/// The account who decremented the counter.
+			who: T::AccountId,
+			/// The amount by which the counter was decremented.
+			decremented_amount: T::CounterValue,
+		},
+		/// An account has incremented its own counter.
+		OwnCounterIncremented {
+			/// The account owning the counter.
+			who: T::AccountId,
+			/// The new value of the account's counter.
+			counter_value: T::CounterValue,
+			/// The amount by which the counter was incremented.
+			incremented_amount: T::CounterValue,
+		},
+		/// An account has decremented its own counter.
+		OwnCounterDecremented {
+			/// The account owning the counter.
+			who: T::AccountId,
+			/// The new value of the account's counter.
+			counter_value: T::CounterValue,
+			/// The amount by which the counter was decremented.
+			decremented_amount: T::CounterValue,
+		},
+		/// An account has reset its own counter.
+		OwnCounterReset {
+			/// The account owning the counter.
+			who: T::AccountId,
+		},
+		/// A counter has been created in the registry.
+		CounterCreated {
+			/// The identifier of the new counter.
+			id: CounterId,
+			/// The owner of the new counter.
+			owner: T::AccountId,
+			/// The maximum value of the new counter.
+			max: T::CounterValue,
+			/// The initial value of the new counter.
+			initial: T::CounterValue,
+		},
+		/// A registry counter has been incremented.
+		RegistryCounterIncremented {
+			/// The identifier of the counter.
+			id: CounterId,
+			/// The new value of the counter.
+			counter_value: T::CounterValue,
+			/// The amount by which the counter was incremented.
+			incremented_amount: T::CounterValue,
+		},
+		/// A registry counter has been decremented.
+		RegistryCounterDecremented {
+			/// The identifier of the counter.
+			id: CounterId,
+			/// The new value of the counter.
+			counter_value: T::CounterValue,
+			/// The amount by which the counter was decremented.
+			decremented_amount: T::CounterValue,
+		},
+		/// The ownership of a registry counter has been offered to another account.
+		CounterOwnershipOffered {
+			/// The identifier of the counter.
+			id: CounterId,
+			/// The current owner.
+			from: T::AccountId,
+			/// The account the counter is offered to.
+			to: T::AccountId,
+		},
+		/// The ownership of a registry counter has been transferred.
+		CounterOwnershipTransferred {
+			/// The identifier of the counter.
+			id: CounterId,
+			/// The previous owner.
+			from: T::AccountId,
+			/// The new owner.
+			to: T::AccountId,
+		},
+		/// A registry counter has been destroyed.
+		CounterDestroyed {
+			/// The identifier of the counter.
+			id: CounterId,
+		},
+		/// An account has cleared its interactions record and got its deposit back.
+		InteractionsCleared {
+			/// The account whose record was cleared.
+			who: T::AccountId,
+		},
+		/// An account has been added to the allow list.
+		AddedToAllowList {
+			/// The account added.
+			who: T::AccountId,
+		},
+		/// An account has been removed from the allow list.
+		RemovedFromAllowList {
+			/// The account removed.
+			who: T::AccountId,
+		},
+		/// The maximum value of the global counter has been overridden or the override removed.
+		MaxValueSet {
+			/// The new override, or `None` if `CounterMaxValue` applies again.
+			max_value: Option<T::CounterValue>,
+		},
+		/// A batch of operations has been applied to the counter.
+		OperationsApplied {
+			/// The account who applied the operations, if any incremented or decremented the
+			/// counter.
+			who: Option<T::AccountId>,
+			/// The number of operations applied.
+			operations: u32,
+			/// The value of the counter after the last operation.
+			counter_value: T::CounterValue,
+		},
+		/// The counter has been reset.
+		CounterReset {
+			/// The era in which the counter was reset. Always zero without a `ResetPeriod`.
+			era: BlockNumberFor<T>,
+		},
+		/// The counter has been scheduled to be set to a new value.
+		CounterValueScheduled {
+			/// The block at which the counter will be set.
+			at: BlockNumberFor<T>,
+			/// The value the counter will be set to.
+			counter_value: T::CounterValue,
+		},
+		/// A scheduled counter value has been cancelled.
+		ScheduledCounterValueCancelled {
+			/// The block at which the counter would have been set.
+			at: BlockNumberFor<T>,
+		},
+		/// The counter has been scheduled to be reset.
+		ResetScheduled {
+			/// The block of the first reset.
+			at: BlockNumberFor<T>,
+			/// The number of blocks between resets, if they repeat.
+			period: Option<BlockNumberFor<T>>,
+		},
+		/// The scheduled reset has been cancelled.
+		ScheduledResetCancelled,
+		/// An account has entered, moved on or left the leaderboard through its interactions.
+		/// Accounts moved down by others, or pushed out of a full leaderboard, are not reported.
+		LeaderboardChanged {
+			/// The account that moved.
+			who: T::AccountId,
+			/// The new index of the account in `TopInteractors`, or `None` if it left it.
+			rank: Option<u32>,
+		},
+		/// An account has been authorized to submit external values.
+		AuthorizedKeyAdded {
+			/// The account authorized.
+			who: T::AccountId,
+		},
+		/// An account is no longer authorized to submit external values.
+		AuthorizedKeyRemoved {
+			/// The account removed.
+			who: T::AccountId,
+		},
+		/// The counter has been set to the value of the external source.
+		ExternalValueSubmitted {
+			/// The authorized account who submitted the value.
+			who: T::AccountId,
+			/// The new value of the counter.
+			counter_value: T::CounterValue,
+		},
+		/// A sibling parachain has successfully incremented the counter.
+		CounterIncrementedFromRemote {
+			/// The new value set.
+			counter_value: T::CounterValue,
+			/// The parachain that incremented the counter.
+			para_id: ParaId,
+			/// The amount by which the counter was incremented.
+			incremented_amount: T::CounterValue,
+		},
+		/// An unsigned increment has been included but failed, consuming its nonce.
+		UnsignedIncrementFailed {
+			/// The signer of the increment.
+			who: T::AccountId,
+			/// The reason the increment failed.
+			error: DispatchError,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The counter value exceeds the maximum allowed value.
+		CounterValueExceedsMax,
+		/// The counter value cannot be decremented below zero.
+		CounterValueBelowZero,
+		/// The counter value cannot go below `CounterMinValue`.
+		CounterValueBelowMin,
+		/// Counter amounts cannot be negative.
+		NegativeAmount,
+		/// Overflow occurred in the counter.
+		CounterOverflow,
+		/// Overflow occurred in user interactions.
+		UserInteractionOverflow,
+		/// No counter exists with the given identifier.
+		CounterNotFound,
+		/// The caller does not own the counter.
+		NotCounterOwner,
+		/// No more counter identifiers are available.
+		CounterIdOverflow,
+		/// The account has no interactions record.
+		NoInteractions,
+		/// The account is already on the allow list.
+		AlreadyAllowListed,
+		/// The account is not on the allow list.
+		NotAllowListed,
+		/// The account exceeded `MaxInteractionsPerPeriod` in the current rate period.
+		RateLimited,
+		/// The maximum value would be below the current value of the global counter.
+		MaxValueBelowCounterValue,
+		/// The batch of operations is empty.
+		NoOperations,
+		/// A counter change is already scheduled for this block, or a reset is already scheduled.
+		AlreadyScheduled,
+		/// No such counter change is scheduled.
+		NotScheduled,
+		/// The period between scheduled resets must not be zero.
+		ZeroPeriod,
+		/// The account is already authorized to submit external values.
+		AlreadyAuthorized,
+		/// The account is not authorized to submit external values.
+		NotAuthorized,
+		/// The nonce of the payload is not the next one of its account.
+		InvalidNonce,
+		/// The parachain is not the origin of the message.
+		ParaIdMismatch,
+		/// Overflow occurred in parachain interactions.
+		ParaInteractionOverflow,
+		/// The counter has not been offered to the caller.
+		NotPendingOwner,
+		/// The maximum value would be below `CounterMinValue`.
+		MaxValueBelowMinValue,
+		/// Counter amounts cannot be zero.
+		ZeroAmount,
+		/// The account already has an interactions record, so it must pay for its increments.
+		AlreadyInteracted,
+		/// The block already includes `MaxUnsignedPerBlock` unsigned increments.
+		TooManyUnsignedIncrements,
+		/// The rate period of the account's latest mutation has not ended yet.
+		RatePeriodNotOver,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			match Self::reset_period() {
+				Some(period) if !n.is_zero() && (n % period).is_zero() => {
+					Self::kill_counter_value();
+					if T::ResetInteractions::get() {
+						TopInteractors::<T>::kill();
+					}
+					Self::deposit_event(Event::<T>::CounterReset { era: n / period });
+					T::WeightInfo::on_initialize_reset()
+				},
+				_ => Weight::zero(),
+			}
+		}
+
+		fn offchain_worker(n: BlockNumberFor<T>) {
+			let interval = T::OffchainInterval::get();
+			if interval.is_zero() || !(n % interval).is_zero() {
+				return;
+			}
+
+			if let Err(error) = Self::sync_external_value() {
+				log::warn!(target: LOG_TARGET, "Failed to sync the counter at block {n:?}: {error}");
+			}
+		}
+
+		fn integrity_test() {
+			assert!(
+				T::ResetPeriod::get().is_none_or(|period| !period.is_zero()),
+				"`ResetPeriod` must not be zero"
+			);
+			let Ok(min) = T::CounterValue::try_from(T::CounterMinValue::get()) else {
+				panic!("`CounterMinValue` must be representable by `CounterValue`");
+			};
+			assert!(
+				min <= T::CounterMaxValue::get(),
+				"`CounterMinValue` must not exceed `CounterMaxValue`"
+			);
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the value of the counter.
+		///
+		/// The dispatch origin of this call must be _Root_.
+		///
+		/// - `new_value`: The new value to set for the counter.
+		///
+		/// Emits `CounterValueSet` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_counter_value())]
+		pub fn set_counter_value(
+			origin: OriginFor<T>,
+			new_value: T::CounterValue,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ensure!(new_value <= Self::max_value(), Error::<T>::CounterValueExceedsMax);
+			ensure!(new_value >= Self::min_value(), Self::below_min_error());
+
+			Self::put_counter_value(new_value);
+
+			Self::deposit_event(Event::<T>::CounterValueSet { counter_value: new_value });
+
+			Ok(())
+		}
+
+		/// Increment the counter by a specified amount.
+		///
+		/// The dispatch origin of this call must be `IncrementOrigin`.
+		///
+		/// - `amount_to_increment`: The amount by which to increment the counter.
+		///
+		/// Emits `CounterIncremented` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(
+			T::WeightInfo::increment().max(T::WeightInfo::increment_existing_interactor())
+		)]
+		pub fn increment(
+			origin: OriginFor<T>,
+			amount_to_increment: T::CounterValue,
+		) -> DispatchResult {
+			let who = T::IncrementOrigin::ensure_origin(origin)?;
+
+			Self::do_increment(who, amount_to_increment, true)
+		}
+
+		/// Decrement the counter by a specified amount.
+		///
+		/// The dispatch origin of this call must be `DecrementOrigin`.
+		///
+		/// - `amount_to_decrement`: The amount by which to decrement the counter.
+		///
+		/// Emits `CounterDecremented` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(
+			T::WeightInfo::decrement().max(T::WeightInfo::decrement_existing_interactor())
+		)]
+		pub fn decrement(
+			origin: OriginFor<T>,
+			amount_to_decrement: T::CounterValue,
+		) -> DispatchResult {
+			let who = T::DecrementOrigin::ensure_origin(origin)?;
+
+			Self::do_decrement(who, amount_to_decrement)
+		}
+
+		/// Increment the caller's own counter by a specified amount.
+		///
+		/// The dispatch origin of this call must be _Signed_. The counter is bounded by the same
+		/// `CounterMaxValue` as the global counter. Creating the counter holds the
+		/// `CounterDeposit` from the caller.
+		///
+		/// - `amount_to_increment`: The amount by which to increment the counter.
+		///
+		/// Emits `OwnCounterIncremented` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::increment_own())]
+		pub fn increment_own(
+			origin: OriginFor<T>,
+			amount_to_increment: T::CounterValue,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let new_value = CountersByAccount::<T>::try_mutate(
+				&who,
+				|value| -> Result<T::CounterValue, DispatchError> {
+					let new_value = Self::checked_increase(
+						value.unwrap_or_else(Zero::zero),
+						amount_to_increment,
+					)?;
+					ensure!(
+						new_value <= T::CounterMaxValue::get(),
+						Error::<T>::CounterValueExceedsMax
+					);
+					if value.is_none() {
+						Self::hold_own_counter_deposit(&who)?;
+					}
+					*value = Some(new_value);
+					Ok(new_value)
+				},
+			)?;
+
+			Self::deposit_event(Event::<T>::OwnCounterIncremented {
+				who,
+				counter_value: new_value,
+				incremented_amount: amount_to_increment,
+			});
+
+			Ok(())
+		}
+
+		/// Decrement the caller's own counter by a specified amount.
+		///
+		/// The dispatch origin of this call must be _Signed_. Creating the counter holds the
+		/// `CounterDeposit` from the caller.
+		///
+		/// - `amount_to_decrement`: The amount by which to decrement the counter.
+		///
+		/// Emits `OwnCounterDecremented` event when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::decrement_own())]
+		pub fn decrement_own(
+			origin: OriginFor<T>,
+			amount_to_decrement: T::CounterValue,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let new_value = CountersByAccount::<T>::try_mutate(
+				&who,
+				|value| -> Result<T::CounterValue, DispatchError> {
+					let new_value = Self::checked_decrease(
+						value.unwrap_or_else(Zero::zero),
+						amount_to_decrement,
+					)?;
+					if value.is_none() {
+						Self::hold_own_counter_deposit(&who)?;
+					}
+					*value = Some(new_value);
+					Ok(new_value)
+				},
+			)?;
+
+			Self::deposit_event(Event::<T>::OwnCounterDecremented {
+				who,
+				counter_value: new_value,
+				decremented_amount: amount_to_decrement,
+			});
+
+			Ok(())
+		}
+
+		/// Reset the caller's own counter, removing it from storage and releasing its deposit.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// Emits `OwnCounterReset` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::reset_own())]
+		pub fn reset_own(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			CountersByAccount::<T>::remove(&who);
+			T::Currency::release_all(
+				&HoldReason::OwnCounterDeposit.into(),
+				&who,
+				Precision::BestEffort,
+			)?;
+
+			Self::deposit_event(Event::<T>::OwnCounterReset { who });
+
+			Ok(())
+		}
+
+		/// Create a new named counter in the registry, owned by the caller.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// - `name`: A human readable name for the counter.
+		/// - `max`: The maximum value the counter can hold.
+		/// - `initial`: The initial value of the counter. Must not exceed `max`.
+		///
+		/// Emits `CounterCreated` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::create_counter())]
+		pub fn create_counter(
+			origin: OriginFor<T>,
+			name: BoundedVec<u8, T::MaxNameLength>,
+			max: T::CounterValue,
+			initial: T::CounterValue,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			ensure!(initial <= max, Error::<T>::CounterValueExceedsMax);
+			ensure!(initial >= Self::min_value(), Self::below_min_error());
+
+			let id = NextCounterId::<T>::get();
+			let next_id = id.checked_add(1).ok_or(Error::<T>::CounterIdOverflow)?;
+
+			let deposit = T::CounterDeposit::get();
+			T::Currency::hold(&HoldReason::CounterDeposit.into(), &owner, deposit)?;
+
+			Counters::<T>::insert(
+				id,
+				CounterInfo {
+					owner: owner.clone(),
+					name,
+					value: initial,
+					max,
+					created_at: frame_system::Pallet::<T>::block_number(),
+					deposit,
+				},
+			);
+			NextCounterId::<T>::put(next_id);
+
+			Self::deposit_event(Event::<T>::CounterCreated { id, owner, max, initial });
+
+			Ok(())
+		}
+
+		/// Increment a registry counter by a specified amount.
+		///
+		/// The dispatch origin of this call must be _Signed_ by the owner of the counter.
+		///
+		/// - `id`: The identifier of the counter.
+		/// - `amount_to_increment`: The amount by which to increment the counter.
+		///
+		/// Emits `RegistryCounterIncremented` event when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::increment_counter())]
+		pub fn increment_counter(
+			origin: OriginFor<T>,
+			id: CounterId,
+			amount_to_increment: T::CounterValue,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let new_value = Self::mutate_owned_counter(id, &who, |info| {
+				let new_value = Self::checked_increase(info.value, amount_to_increment)?;
+				ensure!(new_value <= info.max, Error::<T>::CounterValueExceedsMax);
+				info.value = new_value;
+				Ok(new_value)
+			})?;
+
+			Self::deposit_event(Event::<T>::RegistryCounterIncremented {
+				id,
+				counter_value: new_value,
+				incremented_amount: amount_to_increment,
+			});
+
+			Ok(())
+		}
+
+		/// Decrement a registry counter by a specified amount.
+		///
+		/// The dispatch origin of this call must be _Signed_ by the owner of the counter.
+		///
+		/// - `id`: The identifier of the counter.
+		/// - `amount_to_decrement`: The amount by which to decrement the counter.
+		///
+		/// Emits `RegistryCounterDecremented` event when successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::decrement_counter())]
+		pub fn decrement_counter(
+			origin: OriginFor<T>,
+			id: CounterId,
+			amount_to_decrement: T::CounterValue,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let new_value = Self::mutate_owned_counter(id, &who, |info| {
+				info.value = Self::checked_decrease(info.value, amount_to_decrement)?;
+				Ok(info.value)
+			})?;
+
+			Self::deposit_event(Event::<T>::RegistryCounterDecremented {
+				id,
+				counter_value: new_value,
+				decremented_amount: amount_to_decrement,
+			});
+
+			Ok(())
+		}
+
+		/// Offer the ownership of a registry counter to another account.
+		///
+		/// The dispatch origin of this call must be _Signed_ by the owner of the counter. The
+		/// ownership is only transferred once `new_owner` accepts it through
+		/// `accept_counter_ownership`. A new offer replaces the previous one.
+		///
+		/// - `id`: The identifier of the counter.
+		/// - `new_owner`: The account the counter is offered to.
+		///
+		/// Emits `CounterOwnershipOffered` event when successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::transfer_counter_ownership())]
+		pub fn transfer_counter_ownership(
+			origin: OriginFor<T>,
+			id: CounterId,
+			new_owner: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let new_owner = T::Lookup::lookup(new_owner)?;
+
+			let info = Counters::<T>::get(id).ok_or(Error::<T>::CounterNotFound)?;
+			ensure!(info.owner == who, Error::<T>::NotCounterOwner);
+
+			PendingCounterOwners::<T>::insert(id, &new_owner);
+
+			Self::deposit_event(Event::<T>::CounterOwnershipOffered {
+				id,
+				from: who,
+				to: new_owner,
+			});
+
+			Ok(())
+		}
+
+		/// Accept the ownership of a registry counter offered to the caller.
+		///
+		/// The dispatch origin of this call must be _Signed_ by the account the counter was
+		/// offered to. The counter deposit is released to the previous owner and held from the
+		/// caller.
+		///
+		/// - `id`: The identifier of the counter.
+		///
+		/// Emits `CounterOwnershipTransferred` event when successful.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::accept_counter_ownership())]
+		pub fn accept_counter_ownership(origin: OriginFor<T>, id: CounterId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				PendingCounterOwners::<T>::get(id).as_ref() == Some(&who),
+				Error::<T>::NotPendingOwner
+			);
+
+			let from = Counters::<T>::try_mutate(id, |maybe_info| {
+				let info = maybe_info.as_mut().ok_or(Error::<T>::CounterNotFound)?;
+				let reason = HoldReason::CounterDeposit.into();
+				T::Currency::hold(&reason, &who, info.deposit)?;
+				T::Currency::release(&reason, &info.owner, info.deposit, Precision::BestEffort)?;
+				Ok::<_, DispatchError>(core::mem::replace(&mut info.owner, who.clone()))
+			})?;
+			PendingCounterOwners::<T>::remove(id);
+
+			Self::deposit_event(Event::<T>::CounterOwnershipTransferred { id, from, to: who });
+
+			Ok(())
+		}
+
+		/// Remove a counter from the registry, releasing its deposit.
+		///
+		/// The dispatch origin of this call must be _Signed_ by the owner of the counter. A pending
+		/// offer of the counter is withdrawn.
+		///
+		/// - `id`: The identifier of the counter.
+		///
+		/// Emits `CounterDestroyed` event when successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::destroy_counter())]
+		pub fn destroy_counter(origin: OriginFor<T>, id: CounterId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let info = Counters::<T>::get(id).ok_or(Error::<T>::CounterNotFound)?;
+			ensure!(info.owner == who, Error::<T>::NotCounterOwner);
+
+			Counters::<T>::remove(id);
+			PendingCounterOwners::<T>::remove(id);
+			T::Currency::release(
+				&HoldReason::CounterDeposit.into(),
+				&who,
+				info.deposit,
+				Precision::BestEffort,
+			)?;
+
+			Self::deposit_event(Event::<T>::CounterDestroyed { id });
+
+			Ok(())
+		}
+
+		/// Remove the caller's interactions record and rate limit, releasing its deposit.
+		///
+		/// The dispatch origin of this call must be _Signed_. The rate period of the caller's
+		/// latest mutation must have ended, so clearing does not lift the rate limit.
+		///
+		/// Emits `InteractionsCleared` event when successful.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::clear_interactions())]
+		pub fn clear_interactions(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				Self::current_rate_limit(&who).is_none_or(|(_, count)| count.is_zero()),
+				Error::<T>::RatePeriodNotOver
+			);
+			RateLimits::<T>::remove(&who);
+			let record = UserInteractions::<T>::take(&who).ok_or(Error::<T>::NoInteractions)?;
+			T::Currency::release_all(
+				&HoldReason::InteractionDeposit.into(),
+				&who,
+				Precision::BestEffort,
+			)?;
+
+			let previous = if Self::is_current(&record) { record.count } else { 0 };
+			Self::update_leaderboard(&who, previous, 0);
+
+			Self::deposit_event(Event::<T>::InteractionsCleared { who });
+
+			Ok(())
+		}
+
+		/// Add an account to the allow list.
+		///
+		/// The dispatch origin of this call must be `AdminOrigin`.
+		///
+		/// - `who`: The account to add.
+		///
+		/// Emits `AddedToAllowList` event when successful.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::add_to_allow_list())]
+		pub fn add_to_allow_list(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+
+			ensure!(!AllowList::<T>::contains_key(&who), Error::<T>::AlreadyAllowListed);
+
+			AllowList::<T>::insert(&who, ());
+
+			Self::deposit_event(Event::<T>::AddedToAllowList { who });
+
+			Ok(())
+		}
+
+		/// Remove an account from the allow list.
+		///
+		/// The dispatch origin of this call must be `AdminOrigin`.
+		///
+		/// - `who`: The account to remove.
+		///
+		/// Emits `RemovedFromAllowList` event when successful.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::remove_from_allow_list())]
+		pub fn remove_from_allow_list(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+
+			ensure!(AllowList::<T>::contains_key(&who), Error::<T>::NotAllowListed);
+
+			AllowList::<T>::remove(&who);
+
+			Self::deposit_event(Event::<T>::RemovedFromAllowList { who });
+
+			Ok(())
+		}
+
+		/// Override the maximum value of the global counter.
+		///
+		/// The dispatch origin of this call must be `AdminOrigin`.
+		///
+		/// - `max_value`: The new maximum, or `None` to fall back to `CounterMaxValue`. The
+		///   resulting maximum must not be below the current value of the counter, nor below
+		///   `CounterMinValue`.
+		///
+		/// Emits `MaxValueSet` event when successful.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::set_max_value())]
+		pub fn set_max_value(
+			origin: OriginFor<T>,
+			max_value: Option<T::CounterValue>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let new_max = max_value.unwrap_or_else(T::CounterMaxValue::get);
+			ensure!(new_max >= Self::counter_value(), Error::<T>::MaxValueBelowCounterValue);
+			ensure!(new_max >= Self::min_value(), Error::<T>::MaxValueBelowMinValue);
+
+			MaxValueOverride::<T>::set(max_value);
+
+			Self::deposit_event(Event::<T>::MaxValueSet { max_value });
+
+			Ok(())
+		}
+
+		/// Apply a batch of operations to the counter, in order.
+		///
+		/// The dispatch origin of this call must be `IncrementOrigin` if the batch contains an
+		/// `Increment`, `DecrementOrigin` if it contains a `Decrement` and _Root_ if it contains a
+		/// `Set`. Every operation is checked against the bounds of the counter as if dispatched on
+		/// its own, and the whole batch fails if any operation does. An account incrementing or
+		/// decrementing the counter is charged a single interaction for the whole batch.
+		///
+		/// - `operations`: The operations to apply, at most `MaxOpsPerCall`.
+		///
+		/// Emits `OperationsApplied` event when successful.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::apply_operations(operations.len() as u32))]
+		pub fn apply_operations(
+			origin: OriginFor<T>,
+			operations: BoundedVec<CounterOp<T::CounterValue>, T::MaxOpsPerCall>,
+		) -> DispatchResult {
+			ensure!(!operations.is_empty(), Error::<T>::NoOperations);
+
+			let contains = |f: fn(&CounterOp<T::CounterValue>) -> bool| operations.iter().any(f);
+			if contains(|op| matches!(op, CounterOp::Set(_))) {
+				ensure_root(origin.clone())?;
+			}
+			let incrementer = if contains(|op| matches!(op, CounterOp::Increment(_))) {
+				Some(T::IncrementOrigin::ensure_origin(origin.clone())?)
+			} else {
+				None
+			};
+			let decrementer = if contains(|op| matches!(op, CounterOp::Decrement(_))) {
+				Some(T::DecrementOrigin::ensure_origin(origin)?)
+			} else {
+				None
+			};
+
+			let who = incrementer.or(decrementer);
+			if let Some(who) = &who {
+				Self::ensure_within_rate_limit(who)?;
+			}
+
+			let max_value = Self::max_value();
+			let min_value = Self::min_value();
+			let mut value = Self::counter_value();
+			for op in &operations {
+				value = match *op {
+					CounterOp::Increment(amount) => Self::checked_increase(value, amount)?,
+					CounterOp::Decrement(amount) => Self::checked_decrease(value, amount)?,
+					CounterOp::Set(new_value) => {
+						ensure!(new_value >= min_value, Self::below_min_error());
+						new_value
+					},
+				};
+				ensure!(value <= max_value, Error::<T>::CounterValueExceedsMax);
+			}
+
+			Self::put_counter_value(value);
+
+			if let Some(who) = &who {
+				Self::record_interaction(who, true)?;
+			}
+
+			Self::deposit_event(Event::<T>::OperationsApplied {
+				who,
+				operations: operations.len() as u32,
+				counter_value: value,
+			});
+
+			Ok(())
+		}
+
+		/// Reset the counter to its initial state.
+		///
+		/// The dispatch origin of this call must be _Root_.
+		///
+		/// Emits `CounterReset` event when successful.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::reset_counter())]
+		pub fn reset_counter(origin: OriginFor<T>) -> DispatchResult {
+			ensure_root(origin)?;
+
+			Self::kill_counter_value();
+
+			Self::deposit_event(Event::<T>::CounterReset { era: Self::current_era() });
+
+			Ok(())
+		}
+
+		/// Schedule [`Pallet::set_counter_value`] to be dispatched as Root at block `at`.
+		///
+		/// The dispatch origin of this call must be `AdminOrigin`. The new value is checked
+		/// against the bounds of the counter when the scheduled call is dispatched. At most one
+		/// counter value can be scheduled per block.
+		///
+		/// - `at`: The block at which to set the counter.
+		/// - `new_value`: The value to set the counter to.
+		///
+		/// Emits `CounterValueScheduled` event when successful.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::schedule_counter_value())]
+		pub fn schedule_counter_value(
+			origin: OriginFor<T>,
+			at: BlockNumberFor<T>,
+			new_value: T::CounterValue,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let call = Call::<T>::set_counter_value { new_value };
+			Self::schedule(Self::counter_value_task(at), at, None, call)?;
+
+			Self::deposit_event(Event::<T>::CounterValueScheduled { at, counter_value: new_value });
+
+			Ok(())
+		}
+
+		/// Cancel the counter value scheduled for block `at`.
+		///
+		/// The dispatch origin of this call must be `AdminOrigin`.
+		///
+		/// - `at`: The block the counter value was scheduled for.
+		///
+		/// Emits `ScheduledCounterValueCancelled` event when successful.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::cancel_scheduled_counter_value())]
+		pub fn cancel_scheduled_counter_value(
+			origin: OriginFor<T>,
+			at: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Self::cancel(Self::counter_value_task(at))?;
+
+			Self::deposit_event(Event::<T>::ScheduledCounterValueCancelled { at });
+
+			Ok(())
+		}
+
+		/// Schedule [`Pallet::reset_counter`] to be dispatched as Root at block `at`, and then
+		/// every `period` blocks if given.
+		///
+		/// The dispatch origin of this call must be `AdminOrigin`. Only one reset can be
+		/// scheduled at a time.
+		///
+		/// - `at`: The block of the first reset.
+		/// - `period`: The number of blocks between resets, or `None` to reset only once.
+		///
+		/// Emits `ResetScheduled` event when successful.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::schedule_reset())]
+		pub fn schedule_reset(
+			origin: OriginFor<T>,
+			at: BlockNumberFor<T>,
+			period: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(!period.is_some_and(|period| period.is_zero()), Error::<T>::ZeroPeriod);
+
+			Self::schedule(Self::reset_task(), at, period, Call::<T>::reset_counter {})?;
+
+			Self::deposit_event(Event::<T>::ResetScheduled { at, period });
+
+			Ok(())
+		}
+
+		/// Cancel the scheduled reset, including all further repetitions.
+		///
+		/// The dispatch origin of this call must be `AdminOrigin`.
+		///
+		/// Emits `ScheduledResetCancelled` event when successful.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::cancel_scheduled_reset())]
+		pub fn cancel_scheduled_reset(origin: OriginFor<T>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Self::cancel(Self::reset_task())?;
+
+			Self::deposit_event(Event::<T>::ScheduledResetCancelled);
+
+			Ok(())
+		}
+
+		/// Make the first increment of the counter on behalf of the signer of `payload`, without
+		/// paying fees.
+		///
+		/// The signer is subject to the `IncrementOrigin` and to the rate limit as with
+		/// [`Pallet::increment`], and must not have an interactions record yet. No deposit is held
+		/// for the record created. At most `MaxUnsignedPerBlock` such increments are included in
+		/// a block.
+		///
+		/// The dispatch origin of this call must be _None_. The transaction is only valid if
+		/// `signature` is the signature of `payload` by its `public` key for this chain, `payload`
+		/// carries the next nonce of its account in [`UnsignedNonces`], and the increment would
+		/// currently succeed. The nonce is consumed even if the increment fails at dispatch, so
+		/// the payload cannot be replayed.
+		///
+		/// - `payload`: The non-zero amount to increment the counter by, the signer, its nonce and
+		///   the genesis hash of the chain.
+		/// - `signature`: The signature of the encoded `payload`.
+		///
+		/// Emits `CounterIncremented` event when successful, and `UnsignedIncrementFailed` when
+		/// the increment fails.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::increment_unsigned())]
+		pub fn increment_unsigned(
+			origin: OriginFor<T>,
+			payload: IncrementPayload<T::Public, T::CounterValue, T::Hash>,
+			signature: T::Signature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			// Checked by `validate_unsigned`, which also runs right before dispatch.
+			let _ = signature;
+			ensure!(!payload.amount.is_zero(), Error::<T>::ZeroAmount);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let included = Self::unsigned_increments_in_block();
+			ensure!(
+				included < T::MaxUnsignedPerBlock::get(),
+				Error::<T>::TooManyUnsignedIncrements
+			);
+			UnsignedIncrementsInBlock::<T>::put((now, included.saturating_add(1)));
+
+			let who = payload.public.into_account();
+			UnsignedNonces::<T>::try_mutate(&who, |nonce| {
+				ensure!(payload.nonce == *nonce, Error::<T>::InvalidNonce);
+				*nonce = nonce.saturating_add(1);
+				Ok::<_, DispatchError>(())
+			})?;
+
+			// The increment is rolled back on its own, keeping the nonce consumed.
+			let result = with_storage_layer(|| {
+				let who = T::IncrementOrigin::ensure_origin(
+					frame_system::RawOrigin::Signed(who.clone()).into(),
+				)?;
+				ensure!(!UserInteractions::<T>::contains_key(&who), Error::<T>::AlreadyInteracted);
+				Self::do_increment(who, payload.amount, false)
+			});
+			if let Err(error) = result {
+				Self::deposit_event(Event::<T>::UnsignedIncrementFailed { who, error });
+			}
+
+			Ok(())
+		}
+
+		/// Authorize an account to submit external values.
+		///
+		/// The dispatch origin of this call must be `AdminOrigin`.
+		///
+		/// - `who`: The account to authorize.
+		///
+		/// Emits `AuthorizedKeyAdded` event when successful.
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::add_authorized_key())]
+		pub fn add_authorized_key(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+
+			ensure!(!AuthorizedKeys::<T>::contains_key(&who), Error::<T>::AlreadyAuthorized);
+
+			AuthorizedKeys::<T>::insert(&who, ());
+
+			Self::deposit_event(Event::<T>::AuthorizedKeyAdded { who });
+
+			Ok(())
+		}
+
+		/// Revoke an account's authorization to submit external values.
+		///
+		/// The dispatch origin of this call must be `AdminOrigin`.
+		///
+		/// - `who`: The account to remove.
+		///
+		/// Emits `AuthorizedKeyRemoved` event when successful.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::remove_authorized_key())]
+		pub fn remove_authorized_key(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+
+			ensure!(AuthorizedKeys::<T>::contains_key(&who), Error::<T>::NotAuthorized);
+
+			AuthorizedKeys::<T>::remove(&who);
+
+			Self::deposit_event(Event::<T>::AuthorizedKeyRemoved { who });
+
+			Ok(())
+		}
+
+		/// Set the counter to the value of the external source.
+		///
+		/// Submitted by the off-chain worker, the value is not counted as an interaction and is
+		/// not rate limited.
+		///
+		/// The dispatch origin of this call must be _Signed_ by an account on the
+		/// `AuthorizedKeys`.
+		///
+		/// - `counter_value`: The value of the external source.
+		///
+		/// Emits `ExternalValueSubmitted` event when successful.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::submit_external_value())]
+		pub fn submit_external_value(
+			origin: OriginFor<T>,
+			counter_value: T::CounterValue,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(AuthorizedKeys::<T>::contains_key(&who), Error::<T>::NotAuthorized);
+
+			ensure!(counter_value <= Self::max_value(), Error::<T>::CounterValueExceedsMax);
+			ensure!(counter_value >= Self::min_value(), Self::below_min_error());
+
+			Self::put_counter_value(counter_value);
+
+			Self::deposit_event(Event::<T>::ExternalValueSubmitted { who, counter_value });
+
+			Ok(())
+		}
+
+		/// Increment the counter on behalf of a sibling parachain.
+		///
+		/// Sent by the parachain in an XCM `Transact`, the increment is recorded in
+		/// [`ParaInteractions`]. It is not rate limited and holds no deposit.
+		///
+		/// The dispatch origin of this call must be `XcmOrigin`.
+		///
+		/// - `para_id`: The parachain sending the message, which must match the origin.
+		/// - `amount_to_increment`: The amount by which to increment the counter.
+		///
+		/// Emits `CounterIncrementedFromRemote` event when successful.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::increment_from_remote())]
+		pub fn increment_from_remote(
+			origin: OriginFor<T>,
+			para_id: ParaId,
+			amount_to_increment: T::CounterValue,
+		) -> DispatchResult {
+			let origin_para_id = T::XcmOrigin::ensure_origin(origin)?;
+			ensure!(origin_para_id == para_id, Error::<T>::ParaIdMismatch);
+
+			let current_value = CounterValue::<T>::get().unwrap_or_else(Zero::zero);
+
+			let new_value = Self::checked_increase(current_value, amount_to_increment)?;
+
+			ensure!(new_value <= Self::max_value(), Error::<T>::CounterValueExceedsMax);
+
+			Self::put_counter_value(new_value);
+
+			Self::record_para_interaction(para_id)?;
+
+			Self::deposit_event(Event::<T>::CounterIncrementedFromRemote {
+				counter_value: new_value,
+				para_id,
+				incremented_amount: amount_to_increment,
+			});
+
+			Ok(())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let Call::increment_unsigned { payload, signature } = call else {
+				return InvalidTransaction::Call.into();
+			};
+			let (who, next_nonce) = Self::check_increment_payload(payload, signature)?;
+			Self::check_unsigned_increment(&who, payload.amount)?;
+
+			let mut validity = ValidTransaction::with_tag_prefix("CustomPalletIncrementUnsigned")
+				.priority(T::UnsignedPriority::get())
+				.and_provides((who.clone(), payload.nonce))
+				.longevity(T::UnsignedLongevity::get())
+				.propagate(true);
+			// Payloads ahead of the next nonce wait for the previous one of their account.
+			if payload.nonce > next_nonce {
+				validity = validity.and_requires((who, payload.nonce - 1));
+			}
+			validity.build()
+		}
+
+		fn pre_dispatch(call: &Self::Call) -> Result<(), TransactionValidityError> {
+			let Call::increment_unsigned { payload, signature } = call else {
+				return Err(InvalidTransaction::Call.into());
+			};
+			let (who, next_nonce) = Self::check_increment_payload(payload, signature)?;
+			ensure!(payload.nonce == next_nonce, InvalidTransaction::Future);
+			ensure!(
+				Self::unsigned_increments_in_block() < T::MaxUnsignedPerBlock::get(),
+				InvalidTransaction::ExhaustsResources
+			);
+			Self::check_unsigned_increment(&who, payload.amount)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Check that `signature` signs `payload` for this chain and that the nonce of `payload` is
+		/// not stale.
+		///
+		/// Returns the signer of `payload` and the next nonce of its account.
+		fn check_increment_payload(
+			payload: &IncrementPayload<T::Public, T::CounterValue, T::Hash>,
+			signature: &T::Signature,
+		) -> Result<(T::AccountId, u32), TransactionValidityError> {
+			let who = payload.public.clone().into_account();
+			ensure!(
+				payload.using_encoded(|encoded| signature.verify(encoded, &who)),
+				InvalidTransaction::BadProof
+			);
+			let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+			ensure!(payload.genesis_hash == genesis_hash, InvalidTransaction::BadProof);
+
+			let next_nonce = UnsignedNonces::<T>::get(&who);
+			ensure!(payload.nonce >= next_nonce, InvalidTransaction::Stale);
+			Ok((who, next_nonce))
+		}
+
+		/// Check that an unsigned increment of the global counter by `amount` on behalf of `who`
+		/// would currently succeed, so failing increments take no room in blocks.
+		fn check_unsigned_increment(
+			who: &T::AccountId,
+			amount: T::CounterValue,
+		) -> Result<(), TransactionValidityError> {
+			T::IncrementOrigin::try_origin(frame_system::RawOrigin::Signed(who.clone()).into())
+				.map_err(|_| InvalidTransaction::BadSigner)?;
+			ensure!(!Self::is_rate_limited(who), ValidityError::RateLimited);
+			ensure!(!UserInteractions::<T>::contains_key(who), ValidityError::AlreadyInteracted);
+			ensure!(amount >= Zero::zero(), ValidityError::NegativeAmount);
+			ensure!(!amount.is_zero(), ValidityError::ZeroAmount);
+			ensure!(
+				Self::counter_value()
+					.checked_add(&amount)
+					.is_some_and(|new_value| new_value <= Self::max_value()),
+				ValidityError::CounterValueExceedsMax
+			);
+			Ok(())
+		}
+
+		/// The number of [`Pallet::increment_unsigned`] transactions included in the current block.
+		fn unsigned_increments_in_block() -> u32 {
+			match UnsignedIncrementsInBlock::<T>::get() {
+				Some((block, included)) if block == frame_system::Pallet::<T>::block_number() =>
+					included,
+				_ => 0,
+			}
+		}
+
+		/// Increment the global counter by `amount` on behalf of `who`, and record the
+		/// interaction.
+		pub(crate) fn do_increment(
+			who: T::AccountId,
+			amount: T::CounterValue,
+			hold_deposit: bool,
+		) -> DispatchResult {
+			Self::ensure_within_rate_limit(&who)?;
+
+			let current_value = CounterValue::<T>::get().unwrap_or_else(Zero::zero);
+
+			let new_value = Self::checked_increase(current_value, amount)?;
+
+			ensure!(new_value <= Self::max_value(), Error::<T>::CounterValueExceedsMax);
+
+			Self::put_counter_value(new_value);
+
+			Self::record_interaction(&who, hold_deposit)?;
+
+			Self::deposit_event(Event::<T>::CounterIncremented {
+				counter_value: new_value,
+				who,
+				incremented_amount: amount,
+			});
+
+			Ok(())
+		}
+
+		/// Decrement the global counter by `amount` on behalf of `who`, and record the
+		/// interaction.
+		pub(crate) fn do_decrement(who: T::AccountId, amount: T::CounterValue) -> DispatchResult {
+			Self::ensure_within_rate_limit(&who)?;
+
+			let current_value = CounterValue::<T>::get().unwrap_or_else(Zero::zero);
+
+			let new_value = Self::checked_decrease(current_value, amount)?;
+
+			Self::put_counter_value(new_value);
+
+			Self::record_interaction(&who, true)?;
+
+			Self::deposit_event(Event::<T>::CounterDecremented {
+				counter_value: new_value,
+				who,
+				decremented_amount: amount,
+			});
+
+			Ok(())
+		}
+
+		/// Bump the number of interactions recorded for `who`.
+		///
+		/// The first interaction of an account creates its record and, with `hold_deposit`,
+		/// holds the `InteractionDeposit` for it.
+		fn record_interaction(who: &T::AccountId, hold_deposit: bool) -> DispatchResult {
+			let (previous, count) = UserInteractions::<T>::try_mutate(who, |interactions| {
+				let (previous, count) = match interactions {
+					Some(record) if Self::is_current(record) => (
+						record.count,
+						record.count.checked_add(1).ok_or(Error::<T>::UserInteractionOverflow)?,
+					),
+					Some(_) => (0, 1),
+					None => {
+						if hold_deposit {
+							T::Currency::hold(
+								&HoldReason::InteractionDeposit.into(),
+								who,
+								T::InteractionDeposit::get(),
+							)?;
+						}
+						(0, 1)
+					},
+				};
+				let last_block = frame_system::Pallet::<T>::block_number();
+				*interactions = Some(InteractionRecord { count, last_block });
+				Ok::<_, DispatchError>((previous, count))
+			})?;
+
+			Self::update_leaderboard(who, previous, count);
+			Ok(())
+		}
+
+		/// Bump the number of interactions recorded for the sibling parachain `para_id`.
+		fn record_para_interaction(para_id: ParaId) -> DispatchResult {
+			ParaInteractions::<T>::try_mutate(para_id, |interactions| {
+				let count = match interactions {
+					Some(record) if Self::is_current(record) =>
+						record.count.checked_add(1).ok_or(Error::<T>::ParaInteractionOverflow)?,
+					_ => 1,
+				};
+				let last_block = frame_system::Pallet::<T>::block_number();
+				*interactions = Some(InteractionRecord { count, last_block });
+				Ok(())
+			})
+		}
+
+		/// Move `who` on the [`TopInteractors`] from `previous` to `count` interactions, where
+		/// zero stands for no current record.
+		///
+		/// Entries with the previous count are found by binary search, and so is the new
+		/// position, behind the accounts that reached `count` first. An account entering a full
+		/// leaderboard pushes the last one out.
+		fn update_leaderboard(who: &T::AccountId, previous: u32, count: u32) {
+			TopInteractors::<T>::mutate(|leaderboard| {
+				let first =
+					leaderboard.partition_point(|(_, interactions)| *interactions > previous);
+				let previous_rank = leaderboard[first..]
+					.iter()
+					.take_while(|(_, interactions)| *interactions == previous)
+					.position(|(account, _)| account == who)
+					.map(|offset| {
+						leaderboard.remove(first + offset);
+						(first + offset) as u32
+					});
+
+				let rank = if count.is_zero() {
+					None
+				} else {
+					let rank =
+						leaderboard.partition_point(|(_, interactions)| *interactions >= count);
+					let entry = (who.clone(), count);
+					leaderboard.force_insert_keep_left(rank, entry).ok().map(|_| rank as u32)
+				};
+
+				if previous_rank != rank {
+					Self::deposit_event(Event::<T>::LeaderboardChanged { who: who.clone(), rank });
+				}
+			});
+		}
+
+		/// The current value of the global counter.
+		pub fn counter_value() -> T::CounterValue {
+			CounterValue::<T>::get().unwrap_or_else(Zero::zero)
+		}
+
+		/// The value the global counter had at the end of block `n`, if still covered by the
+		/// [`CounterHistory`].
+		///
+		/// Returns `None` if `n` precedes the oldest entry of the history.
+		pub fn counter_value_at(n: BlockNumberFor<T>) -> Option<T::CounterValue> {
+			let history = CounterHistory::<T>::get();
+			let entries = history.partition_point(|(block, _)| *block <= n);
+			entries
+				.checked_sub(1)
+				.and_then(|last| history.get(last))
+				.map(|(_, value)| *value)
+		}
+
+		/// Set the global counter to `value` and record it in the [`CounterHistory`].
+		fn put_counter_value(value: T::CounterValue) {
+			CounterValue::<T>::put(value);
+			Self::record_history(value);
+		}
+
+		/// Reset the global counter and record it in the [`CounterHistory`].
+		fn kill_counter_value() {
+			CounterValue::<T>::kill();
+			Self::record_history(Zero::zero());
+		}
+
+		/// Record `value` as the value of the global counter in the current block, dropping the
+		/// oldest entry of a full [`CounterHistory`].
+		fn record_history(value: T::CounterValue) {
+			let now = frame_system::Pallet::<T>::block_number();
+			CounterHistory::<T>::mutate(|history| match history.last_mut() {
+				Some((block, last)) if *block == now => *last = value,
+				_ => {
+					let _ = history.force_insert_keep_right(history.len(), (now, value));
+				},
+			});
+		}
+
+		/// The maximum value of the global counter: the [`MaxValueOverride`] if set, otherwise
+		/// [`Config::CounterMaxValue`].
+		pub fn max_value() -> T::CounterValue {
+			MaxValueOverride::<T>::get().unwrap_or_else(T::CounterMaxValue::get)
+		}
+
+		/// The minimum value of every counter: [`Config::CounterMinValue`] as a
+		/// [`Config::CounterValue`].
+		pub fn min_value() -> T::CounterValue {
+			T::CounterValue::try_from(T::CounterMinValue::get()).unwrap_or_else(|_| Zero::zero())
+		}
+
+		/// The error for a counter going below [`Pallet::min_value`].
+		///
+		/// With a zero minimum this is `CounterValueBelowZero`, as for unsigned counters.
+		fn below_min_error() -> Error<T> {
+			if T::CounterMinValue::get() == 0 {
+				Error::<T>::CounterValueBelowZero
+			} else {
+				Error::<T>::CounterValueBelowMin
+			}
+		}
+
+		/// `value` increased by the non-negative `amount`.
+		fn checked_increase(
+			value: T::CounterValue,
+			amount: T::CounterValue,
+		) -> Result<T::CounterValue, DispatchError> {
+			ensure!(amount >= Zero::zero(), Error::<T>::NegativeAmount);
+			value.checked_add(&amount).ok_or_else(|| Error::<T>::CounterOverflow.into())
+		}
+
+		/// `value` decreased by the non-negative `amount`, provided it stays at or above
+		/// [`Pallet::min_value`].
+		fn checked_decrease(
+			value: T::CounterValue,
+			amount: T::CounterValue,
+		) -> Result<T::CounterValue, DispatchError> {
+			ensure!(amount >= Zero::zero(), Error::<T>::NegativeAmount);
+			value
+				.checked_sub(&amount)
+				.filter(|new_value| *new_value >= Self::min_value())
+				.ok_or_else(|| Self::below_min_error().into())
+		}
+
+		/// The number of times `who` incremented or decremented the global counter, in the
+		/// current era if [`Config::ResetInteractions`] is set.
+		pub fn interactions_of(who: &T::AccountId) -> u32 {
+			UserInteractions::<T>::get(who)
+				.filter(Self::is_current)
+				.map_or(0, |record| record.count)
+		}
+
+		/// Up to `limit` accounts with the most interactions, most active first, as kept in the
+		/// [`TopInteractors`].
+		pub fn top_interactors(limit: u32) -> Vec<(T::AccountId, u32)> {
+			let mut interactors = TopInteractors::<T>::get().into_inner();
+			interactors.truncate(limit as usize);
+			interactors
+		}
+
+		/// [`Config::ResetPeriod`], unless it is unset or zero.
+		fn reset_period() -> Option<BlockNumberFor<T>> {
+			T::ResetPeriod::get().filter(|period| !period.is_zero())
+		}
+
+		/// The era of block `n`: the number of reset periods elapsed at `n`.
+		fn era_of(n: BlockNumberFor<T>) -> BlockNumberFor<T> {
+			Self::reset_period().map_or_else(Zero::zero, |period| n / period)
+		}
+
+		/// The era of the current block.
+		pub fn current_era() -> BlockNumberFor<T> {
+			Self::era_of(frame_system::Pallet::<T>::block_number())
+		}
+
+		/// Whether the interactions of `record` still count, i.e. they are not from a past era
+		/// while [`Config::ResetInteractions`] is set.
+		pub(crate) fn is_current(record: &InteractionRecord<BlockNumberFor<T>>) -> bool {
+			!T::ResetInteractions::get() || Self::era_of(record.last_block) == Self::current_era()
+		}
+
+		/// Count a global counter mutation by `who` against its rate limit.
+		///
+		/// A new period starts with the first mutation after the previous one elapsed.
+		fn ensure_within_rate_limit(who: &T::AccountId) -> DispatchResult {
+			let Some((period_start, count)) = Self::current_rate_limit(who) else {
+				return Ok(());
+			};
+			ensure!(count < T::MaxInteractionsPerPeriod::get(), Error::<T>::RateLimited);
+			RateLimits::<T>::insert(who, (period_start, count.saturating_add(1)));
+			Ok(())
+		}
+
+		/// Whether `who` has used up its mutations of the current period.
+		fn is_rate_limited(who: &T::AccountId) -> bool {
+			Self::current_rate_limit(who)
+				.is_some_and(|(_, count)| count >= T::MaxInteractionsPerPeriod::get())
+		}
+
+		/// The start of the current rate limit period of `who` and the number of its mutations
+		/// in it, or `None` without a rate limit.
+		fn current_rate_limit(who: &T::AccountId) -> Option<(BlockNumberFor<T>, u32)> {
+			let period = T::RatePeriod::get();
+			if period.is_zero() {
+				return None;
+			}
+
+			let now = frame_system::Pallet::<T>::block_number();
+			Some(match RateLimits::<T>::get(who) {
+				Some((start, count)) if now < start.saturating_add(period) => (start, count),
+				_ => (now, 0),
+			})
+		}
+
+		/// The name of the scheduler task setting the counter at block `at`.
+		pub(crate) fn counter_value_task(at: BlockNumberFor<T>) -> TaskName {
+			(*b"custom_pallet", *b"set_counter_value", at).blake2_256()
+		}
+
+		/// The name of the scheduler task resetting the counter.
+		pub(crate) fn reset_task() -> TaskName {
+			(*b"custom_pallet", *b"reset_counter").blake2_256()
+		}
+
+		/// Have the scheduler dispatch `call` as Root at block `at`, and then every `period`
+		/// blocks if given, under the unique name `task`.
+		fn schedule(
+			task: TaskName,
+			at: BlockNumberFor<T>,
+			period: Option<BlockNumberFor<T>>,
+			call: Call<T>,
+		) -> DispatchResult {
+			ensure!(T::Scheduler::next_dispatch_time(task).is_err(), Error::<T>::AlreadyScheduled);
+
+			let call = T::Preimages::bound(<T as frame_system::Config>::RuntimeCall::from(call))?;
+			T::Scheduler::schedule_named(
+				task,
+				DispatchTime::At(at),
+				period.map(|period| (period, u32::MAX)),
+				HARD_DEADLINE,
+				frame_system::RawOrigin::Root.into(),
+				call,
+			)?;
+
+			Ok(())
+		}
+
+		/// Cancel the scheduler task `task`.
+		fn cancel(task: TaskName) -> DispatchResult {
+			ensure!(T::Scheduler::next_dispatch_time(task).is_ok(), Error::<T>::NotScheduled);
+			T::Scheduler::cancel_named(task)
+		}
+
+		/// Fetch the value of the external source and submit it, unless the counter already holds
+		/// it or cannot hold it.
+		fn sync_external_value() -> Result<(), &'static str> {
+			let signer = Signer::<T, T::AuthorityId>::any_account()
+				.with_filter(Self::authorized_local_keys());
+			if !signer.can_sign() {
+				return Err("no authorized local key to sign the external value with");
+			}
+
+			let counter_value = Self::fetch_external_value()?;
+			if counter_value == Self::counter_value() {
+				return Ok(());
+			}
+			if counter_value > Self::max_value() || counter_value < Self::min_value() {
+				return Err("the external value is out of the bounds of the counter");
+			}
+
+			let (_, result) = signer
+				.send_signed_transaction(|_| Call::submit_external_value { counter_value })
+				.ok_or("no local key could sign the external value")?;
+			result.map_err(|()| "the external value could not be submitted")
+		}
+
+		/// Fetch the value of the external source from the URL under [`EXTERNAL_VALUE_URL_KEY`].
+		fn fetch_external_value() -> Result<T::CounterValue, &'static str> {
+			let url =
+				sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, EXTERNAL_VALUE_URL_KEY)
+					.ok_or("no URL to fetch the external value from")?;
+			let url = core::str::from_utf8(&url).map_err(|_| "the URL is not valid UTF-8")?;
+
+			let deadline =
+				sp_io::offchain::timestamp().add(Duration::from_millis(FETCH_TIMEOUT_MS));
+			let response = http::Request::get(url)
+				.deadline(deadline)
+				.send()
+				.map_err(|_| "the request could not be sent")?
+				.try_wait(deadline)
+				.map_err(|_| "the request timed out")?
+				.map_err(|_| "the request failed")?;
+			if response.code != 200 {
+				return Err("the external source answered with an unexpected status");
+			}
+
+			let body = response.body().collect::<Vec<u8>>();
+			parse_external_value(&body).ok_or("the external source answered with an invalid value")
+		}
+
+		/// The keys of the local keystore whose accounts are on the [`AuthorizedKeys`].
+		fn authorized_local_keys() -> Vec<T::Public> {
+			<T::AuthorityId as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::all()
+				.into_iter()
+				.map(|key| {
+					<T::AuthorityId as AppCrypto<T::Public, T::Signature>>::GenericPublic::from(key)
+						.into()
+				})
+				.filter(|public: &T::Public| {
+					AuthorizedKeys::<T>::contains_key(public.clone().into_account())
+				})
+				.collect()
+		}
+
+		/// Hold the `CounterDeposit` from `who` for its private counter.
+		fn hold_own_counter_deposit(who: &T::AccountId) -> DispatchResult {
+			T::Currency::hold(&HoldReason::OwnCounterDeposit.into(), who, T::CounterDeposit::get())
+		}
+
+		/// Apply `f` to the registry counter `id`, provided it exists and is owned by `who`.
+		fn mutate_owned_counter<R>(
+			id: CounterId,
+			who: &T::AccountId,
+			f: impl FnOnce(&mut CounterInfo<T>) -> Result<R, DispatchError>,
+		) -> Result<R, DispatchError> {
+			Counters::<T>::try_mutate(id, |maybe_info| {
+				let info = maybe_info.as_mut().ok_or(Error::<T>::CounterNotFound)?;
+				ensure!(info.owner == *who, Error::<T>::NotCounterOwner);
+				f(info)
+			})
+		}
+	}
+}
+
+/// Ensure the origin is a signed account that is on the [`AllowList`].
+///
+/// Suitable for [`Config::IncrementOrigin`] and [`Config::DecrementOrigin`] to restrict
+/// counter mutations to the accounts approved by [`Config::AdminOrigin`].
+pub struct EnsureAllowListed<T>(core::marker::PhantomData<T>);
+
+impl<T: Config> EnsureOrigin<T::RuntimeOrigin> for EnsureAllowListed<T> {
+	type Success = T::AccountId;
+
+	fn try_origin(o: T::RuntimeOrigin) -> Result<Self::Success, T::RuntimeOrigin> {
+		match o.clone().into() {
+			Ok(RawOrigin::Signed(who)) if AllowList::<T>::contains_key(&who) => Ok(who),
+			_ => Err(o),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<T::RuntimeOrigin, ()> {
+		let who: T::AccountId = frame_benchmarking::whitelisted_caller();
+		AllowList::<T>::insert(&who, ());
+		Ok(RawOrigin::Signed(who).into())
+	}
+}
@@ -0,0 +1,22 @@
+//! Storage migrations for the custom pallet.
+//!
+//! Every migration is wrapped in a
+//! [`VersionedMigration`](frame_support::migrations::VersionedMigration) so it only runs when the
+//! on-chain storage version matches, and bumps the version afterwards. Runtimes add the migrations
+//! they need, in order, to their `Migrations` tuple:
+//!
+//! ```ignore
+//! pub type Migrations = (
+//!     pallet_custom::migrations::v1::MigrateV0ToV1<Runtime>,
+//!     pallet_custom::migrations::v2::MigrateV1ToV2<Runtime>,
+//!     pallet_custom::migrations::v3::MigrateV2ToV3<Runtime, u32>,
+//! );
+//! ```
+//!
+//! Values encoded with one [`Config::CounterValue`](crate::Config::CounterValue) cannot be decoded
+//! with another, so a live chain changing the type, for example from `u32` to `u64`, translates
+//! them through [`v3::MigrateV2ToV3`] with the type they were stored with.
+
+pub mod v1;
+pub mod v2;
+pub mod v3;
@@ -0,0 +1,150 @@
+//! Migration from storage version 0 (the original exercise layout) to version 1.
+//!
+//! Version 0 stored a bare `u32` interaction count per account in [`UserInteractions`]. Version 1
+//! stores an [`InteractionRecord`] holding the count and the block of the latest interaction.
+//! Since the latter is unknown for existing entries, it is set to the block the migration runs
+//! in. [`CounterValue`](crate::CounterValue) keeps its layout and is left untouched.
+
+use crate::{Config, InteractionRecord, Pallet, UserInteractions};
+#[cfg(feature = "try-runtime")]
+use alloc::vec::Vec;
+use frame_support::{
+	migrations::VersionedMigration,
+	pallet_prelude::*,
+	traits::{Get, UncheckedOnRuntimeUpgrade},
+};
+
+/// The storage layout of version 0.
+pub mod v0 {
+	use super::*;
+
+	/// The number of times each account has incremented or decremented the counter.
+	#[frame_support::storage_alias]
+	pub type UserInteractions<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, <T as frame_system::Config>::AccountId, u32>;
+}
+
+/// Translates every [`UserInteractions`] entry to an [`InteractionRecord`].
+///
+/// Not meant to be used directly, use [`MigrateV0ToV1`] instead.
+pub struct InnerMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let now = frame_system::Pallet::<T>::block_number();
+		let mut translated = 0u64;
+
+		UserInteractions::<T>::translate::<u32, _>(|_, count| {
+			translated = translated.saturating_add(1);
+			Some(InteractionRecord { count, last_block: now })
+		});
+
+		T::DbWeight::get().reads_writes(translated, translated)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		let entries = v0::UserInteractions::<T>::iter().count() as u64;
+		let total: u64 = v0::UserInteractions::<T>::iter_values().map(u64::from).sum();
+		let counter_value = crate::CounterValue::<T>::get();
+
+		Ok((entries, total, counter_value).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let (entries, total, counter_value): (u64, u64, Option<T::CounterValue>) =
+			Decode::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the pre-upgrade state")?;
+
+		ensure!(
+			UserInteractions::<T>::iter().count() as u64 == entries,
+			"UserInteractions entries were lost during the migration"
+		);
+		ensure!(
+			UserInteractions::<T>::iter_values()
+				.map(|record| u64::from(record.count))
+				.sum::<u64>() ==
+				total,
+			"UserInteractions counts changed during the migration"
+		);
+		ensure!(
+			crate::CounterValue::<T>::get() == counter_value,
+			"CounterValue changed during the migration"
+		);
+
+		Ok(())
+	}
+}
+
+/// Migrates the pallet storage from version 0 to version 1.
+pub type MigrateV0ToV1<T> = VersionedMigration<
+	0,
+	1,
+	InnerMigrateV0ToV1<T>,
+	Pallet<T>,
+	<T as frame_system::Config>::DbWeight,
+>;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{mock::*, CounterValue};
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+	#[test]
+	fn migration_translates_interactions() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(0).put::<Pallet<Test>>();
+			CounterValue::<Test>::put(7);
+			v0::UserInteractions::<Test>::insert(1, 3);
+			v0::UserInteractions::<Test>::insert(2, u32::MAX);
+			System::set_block_number(42);
+
+			MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+			assert_eq!(
+				UserInteractions::<Test>::get(1),
+				Some(InteractionRecord { count: 3, last_block: 42 })
+			);
+			assert_eq!(
+				UserInteractions::<Test>::get(2),
+				Some(InteractionRecord { count: u32::MAX, last_block: 42 })
+			);
+			assert_eq!(CounterValue::<Test>::get(), Some(7));
+			assert_eq!(Pallet::<Test>::on_chain_storage_version(), 1);
+		});
+	}
+
+	#[test]
+	fn migration_only_runs_once() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(0).put::<Pallet<Test>>();
+			v0::UserInteractions::<Test>::insert(1, 3);
+
+			MigrateV0ToV1::<Test>::on_runtime_upgrade();
+			System::set_block_number(42);
+			MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+			assert_eq!(
+				UserInteractions::<Test>::get(1),
+				Some(InteractionRecord { count: 3, last_block: 1 })
+			);
+		});
+	}
+
+	#[cfg(feature = "try-runtime")]
+	#[test]
+	fn migration_passes_try_runtime_checks() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(0).put::<Pallet<Test>>();
+			CounterValue::<Test>::put(7);
+			v0::UserInteractions::<Test>::insert(1, 3);
+			v0::UserInteractions::<Test>::insert(2, 5);
+
+			let state = MigrateV0ToV1::<Test>::pre_upgrade().unwrap();
+			MigrateV0ToV1::<Test>::on_runtime_upgrade();
+			frame_support::assert_ok!(MigrateV0ToV1::<Test>::post_upgrade(state));
+		});
+	}
+}
@@ -0,0 +1,134 @@
+//! Migration from storage version 1 to version 2.
+//!
+//! Version 2 introduces the [`TopInteractors`] leaderboard, which is kept up to date as
+//! [`UserInteractions`] changes. The migration ranks the existing records on it. Accounts with as
+//! many interactions are ranked in the order of the map, since it is unknown who reached the
+//! number first.
+
+use crate::{Config, Pallet, TopInteractors, UserInteractions};
+use alloc::vec::Vec;
+use frame_support::{
+	migrations::VersionedMigration,
+	pallet_prelude::*,
+	traits::{Get, UncheckedOnRuntimeUpgrade},
+};
+
+/// Fills [`TopInteractors`] from [`UserInteractions`].
+///
+/// Not meant to be used directly, use [`MigrateV1ToV2`] instead.
+pub struct InnerMigrateV1ToV2<T>(core::marker::PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let mut read = 0u64;
+		let mut interactors: Vec<_> = UserInteractions::<T>::iter()
+			.inspect(|_| read = read.saturating_add(1))
+			.filter(|(_, record)| Pallet::<T>::is_current(record))
+			.map(|(who, record)| (who, record.count))
+			.collect();
+		interactors.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+		TopInteractors::<T>::put(BoundedVec::truncate_from(interactors));
+
+		T::DbWeight::get().reads_writes(read, 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		let current = UserInteractions::<T>::iter_values()
+			.filter(|record| Pallet::<T>::is_current(record))
+			.count() as u64;
+
+		Ok(current.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let current: u64 = Decode::decode(&mut &state[..])
+			.map_err(|_| "Failed to decode the pre-upgrade state")?;
+		let leaderboard = TopInteractors::<T>::get();
+
+		ensure!(
+			leaderboard.len() as u64 == current.min(u64::from(T::MaxLeaderboard::get())),
+			"TopInteractors does not hold the most active accounts"
+		);
+		ensure!(
+			leaderboard.windows(2).all(|pair| pair[0].1 >= pair[1].1),
+			"TopInteractors is not sorted"
+		);
+		ensure!(
+			leaderboard
+				.iter()
+				.all(|(who, count)| Pallet::<T>::interactions_of(who) == *count),
+			"TopInteractors does not match UserInteractions"
+		);
+
+		Ok(())
+	}
+}
+
+/// Migrates the pallet storage from version 1 to version 2.
+pub type MigrateV1ToV2<T> = VersionedMigration<
+	1,
+	2,
+	InnerMigrateV1ToV2<T>,
+	Pallet<T>,
+	<T as frame_system::Config>::DbWeight,
+>;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{mock::*, InteractionRecord};
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+	fn insert_record(who: u64, count: u32) {
+		UserInteractions::<Test>::insert(who, InteractionRecord { count, last_block: 1 });
+	}
+
+	#[test]
+	fn migration_ranks_interactions() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(1).put::<Pallet<Test>>();
+			insert_record(1, 3);
+			insert_record(2, 7);
+			insert_record(3, 1);
+			insert_record(4, 5);
+
+			MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+			assert_eq!(TopInteractors::<Test>::get().into_inner(), vec![(2, 7), (4, 5), (1, 3)]);
+			assert_eq!(Pallet::<Test>::on_chain_storage_version(), 2);
+		});
+	}
+
+	#[test]
+	fn migration_only_runs_once() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(1).put::<Pallet<Test>>();
+			insert_record(1, 3);
+
+			MigrateV1ToV2::<Test>::on_runtime_upgrade();
+			insert_record(2, 7);
+			MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+			assert_eq!(TopInteractors::<Test>::get().into_inner(), vec![(1, 3)]);
+		});
+	}
+
+	#[cfg(feature = "try-runtime")]
+	#[test]
+	fn migration_passes_try_runtime_checks() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(1).put::<Pallet<Test>>();
+			insert_record(1, 3);
+			insert_record(2, 7);
+			insert_record(3, 1);
+			insert_record(4, 5);
+
+			let state = MigrateV1ToV2::<Test>::pre_upgrade().unwrap();
+			MigrateV1ToV2::<Test>::on_runtime_upgrade();
+			frame_support::assert_ok!(MigrateV1ToV2::<Test>::post_upgrade(state));
+		});
+	}
+}
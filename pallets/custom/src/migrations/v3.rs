@@ -0,0 +1,234 @@
+//! Migration from storage version 2 to version 3.
+//!
+//! Version 3 stores every counter value with the current [`Config::CounterValue`]. Chains that
+//! widen the type, for example from `u32` to `u64`, run the migration with `Old` set to the type
+//! the values were stored with: [`CounterValue`], [`MaxValueOverride`], [`CounterHistory`],
+//! [`CountersByAccount`] and [`Counters`] are decoded with it and re-encoded with the new type.
+//! Chains keeping their type set `Old` to [`Config::CounterValue`], which leaves the values as
+//! they are.
+//!
+//! Counter amounts held in scheduled calls or in the transaction pool are not translated, so they
+//! should be drained before the upgrade.
+
+use crate::{
+	Config, CounterHistory, CounterInfo, CounterValue, Counters, CountersByAccount,
+	MaxValueOverride, Pallet,
+};
+use alloc::vec::Vec;
+use frame_support::{
+	migrations::VersionedMigration,
+	pallet_prelude::*,
+	traits::{Get, UncheckedOnRuntimeUpgrade},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+
+/// The storage layout of version 2, with values of the `Old` counter type.
+pub mod v2 {
+	use super::*;
+	use crate::BalanceOf;
+
+	/// A named counter in the registry.
+	#[derive(Encode, Decode)]
+	pub struct CounterInfo<T: Config, Old> {
+		/// The account allowed to mutate, transfer and destroy the counter.
+		pub owner: T::AccountId,
+		/// Human readable name of the counter.
+		pub name: BoundedVec<u8, T::MaxNameLength>,
+		/// The current value of the counter.
+		pub value: Old,
+		/// The maximum value the counter can hold.
+		pub max: Old,
+		/// The block at which the counter was created.
+		pub created_at: BlockNumberFor<T>,
+		/// The deposit held from the owner for this counter.
+		pub deposit: BalanceOf<T>,
+	}
+}
+
+/// Translates every counter value from `Old` to [`Config::CounterValue`].
+///
+/// Not meant to be used directly, use [`MigrateV2ToV3`] instead.
+pub struct InnerMigrateV2ToV3<T, Old>(core::marker::PhantomData<(T, Old)>);
+
+impl<T: Config, Old: Decode + Into<T::CounterValue>> UncheckedOnRuntimeUpgrade
+	for InnerMigrateV2ToV3<T, Old>
+{
+	fn on_runtime_upgrade() -> Weight {
+		let mut translated = 3u64;
+
+		let _ = CounterValue::<T>::translate::<Old, _>(|value| value.map(Into::into));
+		let _ = MaxValueOverride::<T>::translate::<Old, _>(|max| max.map(Into::into));
+		let _ = CounterHistory::<T>::translate::<Vec<(BlockNumberFor<T>, Old)>, _>(|history| {
+			history.map(|history| {
+				BoundedVec::truncate_from(
+					history.into_iter().map(|(block, value)| (block, value.into())).collect(),
+				)
+			})
+		});
+		CountersByAccount::<T>::translate::<Old, _>(|_, value| {
+			translated = translated.saturating_add(1);
+			Some(value.into())
+		});
+		Counters::<T>::translate::<v2::CounterInfo<T, Old>, _>(|_, info| {
+			translated = translated.saturating_add(1);
+			Some(CounterInfo {
+				owner: info.owner,
+				name: info.name,
+				value: info.value.into(),
+				max: info.max.into(),
+				created_at: info.created_at,
+				deposit: info.deposit,
+			})
+		});
+
+		T::DbWeight::get().reads_writes(translated, translated)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		use frame_support::storage::unhashed;
+
+		let counter_value = unhashed::get::<Old>(&CounterValue::<T>::hashed_key())
+			.map(Into::<T::CounterValue>::into);
+		let max_value = unhashed::get::<Old>(&MaxValueOverride::<T>::hashed_key())
+			.map(Into::<T::CounterValue>::into);
+		let history =
+			unhashed::get::<Vec<(BlockNumberFor<T>, Old)>>(&CounterHistory::<T>::hashed_key())
+				.map_or(0, |history| history.len() as u64);
+		let private_counters = CountersByAccount::<T>::iter_keys().count() as u64;
+		let counters = Counters::<T>::iter_keys().count() as u64;
+
+		Ok((counter_value, max_value, history, private_counters, counters).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let (counter_value, max_value, history, private_counters, counters): (
+			Option<T::CounterValue>,
+			Option<T::CounterValue>,
+			u64,
+			u64,
+			u64,
+		) = Decode::decode(&mut &state[..]).map_err(|_| "Failed to decode the pre-upgrade state")?;
+
+		ensure!(
+			CounterValue::<T>::get() == counter_value,
+			"CounterValue changed during the migration"
+		);
+		ensure!(
+			MaxValueOverride::<T>::get() == max_value,
+			"MaxValueOverride changed during the migration"
+		);
+		ensure!(
+			CounterHistory::<T>::get().len() as u64 == history,
+			"CounterHistory entries were lost during the migration"
+		);
+		ensure!(
+			CountersByAccount::<T>::iter().count() as u64 == private_counters,
+			"CountersByAccount entries were lost during the migration"
+		);
+		ensure!(
+			Counters::<T>::iter().count() as u64 == counters,
+			"Counters entries were lost during the migration"
+		);
+
+		Ok(())
+	}
+}
+
+/// Migrates the pallet storage from version 2 to version 3, translating counter values from
+/// `Old` to [`Config::CounterValue`].
+pub type MigrateV2ToV3<T, Old> = VersionedMigration<
+	2,
+	3,
+	InnerMigrateV2ToV3<T, Old>,
+	Pallet<T>,
+	<T as frame_system::Config>::DbWeight,
+>;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::*;
+	use frame_support::{
+		storage::unhashed,
+		traits::{OnRuntimeUpgrade, StorageVersion},
+	};
+
+	/// Stores counter values as `u32`, as a chain with a `u32` counter type did.
+	fn insert_u32_values() {
+		StorageVersion::new(2).put::<Pallet<Test>>();
+		unhashed::put(&CounterValue::<Test>::hashed_key(), &7u32);
+		unhashed::put(&MaxValueOverride::<Test>::hashed_key(), &u32::MAX);
+		unhashed::put(&CounterHistory::<Test>::hashed_key(), &vec![(1u64, 5u32), (2u64, 7u32)]);
+		unhashed::put(&CountersByAccount::<Test>::hashed_key_for(1), &3u32);
+		unhashed::put(
+			&Counters::<Test>::hashed_key_for(0),
+			&v2::CounterInfo::<Test, u32> {
+				owner: 2,
+				name: BoundedVec::truncate_from(b"votes".to_vec()),
+				value: 4,
+				max: 9,
+				created_at: 1,
+				deposit: 10,
+			},
+		);
+	}
+
+	#[test]
+	fn migration_widens_u32_values() {
+		new_test_ext().execute_with(|| {
+			insert_u32_values();
+
+			MigrateV2ToV3::<Test, u32>::on_runtime_upgrade();
+
+			assert_eq!(CounterValue::<Test>::get(), Some(7));
+			assert_eq!(MaxValueOverride::<Test>::get(), Some(u32::MAX.into()));
+			assert_eq!(CounterHistory::<Test>::get().into_inner(), vec![(1, 5), (2, 7)]);
+			assert_eq!(CountersByAccount::<Test>::get(1), Some(3));
+			let info = Counters::<Test>::get(0).unwrap();
+			assert_eq!((info.owner, info.value, info.max, info.deposit), (2, 4, 9, 10));
+			assert_eq!(&info.name[..], b"votes");
+			assert_eq!(Pallet::<Test>::on_chain_storage_version(), 3);
+		});
+	}
+
+	#[test]
+	fn migration_keeps_values_of_the_same_type() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(2).put::<Pallet<Test>>();
+			CounterValue::<Test>::put(7);
+			CountersByAccount::<Test>::insert(1, 3);
+
+			MigrateV2ToV3::<Test, <Test as Config>::CounterValue>::on_runtime_upgrade();
+
+			assert_eq!(CounterValue::<Test>::get(), Some(7));
+			assert_eq!(CountersByAccount::<Test>::get(1), Some(3));
+		});
+	}
+
+	#[test]
+	fn migration_only_runs_once() {
+		new_test_ext().execute_with(|| {
+			insert_u32_values();
+
+			MigrateV2ToV3::<Test, u32>::on_runtime_upgrade();
+			MigrateV2ToV3::<Test, u32>::on_runtime_upgrade();
+
+			assert_eq!(CounterValue::<Test>::get(), Some(7));
+			assert_eq!(CountersByAccount::<Test>::get(1), Some(3));
+		});
+	}
+
+	#[cfg(feature = "try-runtime")]
+	#[test]
+	fn migration_passes_try_runtime_checks() {
+		new_test_ext().execute_with(|| {
+			insert_u32_values();
+
+			let state = MigrateV2ToV3::<Test, u32>::pre_upgrade().unwrap();
+			MigrateV2ToV3::<Test, u32>::on_runtime_upgrade();
+			frame_support::assert_ok!(MigrateV2ToV3::<Test, u32>::post_upgrade(state));
+		});
+	}
+}
@@ -0,0 +1,264 @@
+//! A test runtime with an unsigned `u64` counter, see [`signed`] for a signed one.
+
+use crate::{self as pallet_custom, ParaId};
+use codec::Encode;
+use core::marker::PhantomData;
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstU32, EnsureOrigin, EqualPrivilegeOnly},
+	weights::Weight,
+};
+use frame_system::{
+	offchain::{AppCrypto, CreateSignedTransaction, CreateTransactionBase, SigningTypes},
+	EnsureRoot, EnsureSigned,
+};
+use parking_lot::RwLock;
+use sp_core::offchain::{
+	testing::{OffchainState, PoolState, TestOffchainExt, TestTransactionPoolExt},
+	OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+};
+use sp_runtime::{
+	generic::UncheckedExtrinsic,
+	testing::{TestSignature, UintAuthorityId},
+	BuildStorage,
+};
+use std::sync::Arc;
+
+pub mod signed;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+		Balances: pallet_balances,
+		Preimage: pallet_preimage,
+		Scheduler: pallet_scheduler,
+		CustomPallet: pallet_custom,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+	type RuntimeHoldReason = RuntimeHoldReason;
+}
+
+impl pallet_preimage::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Currency = ();
+	type ManagerOrigin = EnsureRoot<u64>;
+	type Consideration = ();
+}
+
+parameter_types! {
+	pub MaximumSchedulerWeight: Weight = Weight::MAX;
+}
+
+impl pallet_scheduler::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type PalletsOrigin = OriginCaller;
+	type RuntimeCall = RuntimeCall;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type ScheduleOrigin = EnsureRoot<u64>;
+	type OriginPrivilegeCmp = EqualPrivilegeOnly;
+	type MaxScheduledPerBlock = ConstU32<10>;
+	type WeightInfo = ();
+	type Preimages = Preimage;
+	type BlockNumberProvider = System;
+}
+
+/// The transactions submitted by the off-chain worker, signed by a [`UintAuthorityId`].
+pub type Extrinsic = UncheckedExtrinsic<u64, RuntimeCall, TestSignature, ()>;
+
+impl SigningTypes for Test {
+	type Public = UintAuthorityId;
+	type Signature = TestSignature;
+}
+
+impl<LocalCall> CreateTransactionBase<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type Extrinsic = Extrinsic;
+	type RuntimeCall = RuntimeCall;
+}
+
+impl<LocalCall> CreateSignedTransaction<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_signed_transaction<C: AppCrypto<Self::Public, Self::Signature>>(
+		call: RuntimeCall,
+		public: UintAuthorityId,
+		account: u64,
+		nonce: Self::Nonce,
+	) -> Option<Extrinsic> {
+		let signature = C::sign(&(&call, nonce).encode(), public)?;
+		Some(Extrinsic::new_signed(call, account, signature, ()))
+	}
+}
+
+/// Signs the off-chain worker transactions with the keys set through
+/// [`UintAuthorityId::set_all_keys`].
+pub struct TestAuthorityId;
+
+impl AppCrypto<UintAuthorityId, TestSignature> for TestAuthorityId {
+	type RuntimeAppPublic = UintAuthorityId;
+	type GenericPublic = UintAuthorityId;
+	type GenericSignature = TestSignature;
+}
+
+parameter_types! {
+	pub const CounterMaxValue: u64 = 10;
+	pub static CounterMinValue: i64 = 0;
+	pub const MaxOpsPerCall: u32 = 4;
+	pub const MaxHistory: u32 = 4;
+	pub const MaxLeaderboard: u32 = 3;
+	pub const MaxNameLength: u32 = 16;
+	pub const CounterDeposit: u64 = 10;
+	pub const InteractionDeposit: u64 = 2;
+	pub const MaxInteractionsPerPeriod: u32 = 3;
+	pub const RatePeriod: u64 = 10;
+	pub static ResetPeriod: Option<u64> = None;
+	pub static ResetInteractions: bool = false;
+	pub static RestrictMutations: bool = false;
+	pub const OffchainInterval: u64 = 5;
+	pub const UnsignedPriority: u64 = 100;
+	pub const UnsignedLongevity: u64 = 8;
+	pub const MaxUnsignedPerBlock: u32 = 3;
+}
+
+/// Admits any signed account, or only allow-listed ones once [`RestrictMutations`] is set.
+pub struct MutationOrigin;
+
+impl EnsureOrigin<RuntimeOrigin> for MutationOrigin {
+	type Success = u64;
+
+	fn try_origin(o: RuntimeOrigin) -> Result<u64, RuntimeOrigin> {
+		if RestrictMutations::get() {
+			pallet_custom::EnsureAllowListed::<Test>::try_origin(o)
+		} else {
+			EnsureSigned::<u64>::try_origin(o)
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+		pallet_custom::EnsureAllowListed::<Test>::try_successful_origin()
+	}
+}
+
+/// The accounts standing for sibling parachains: signed origins of account
+/// `PARA_ACCOUNT_OFFSET + n` are treated as XCM origins of parachain `n`.
+pub const PARA_ACCOUNT_OFFSET: u64 = 1_000;
+
+/// Admits the signed origins of the accounts standing for sibling parachains, as a runtime would
+/// admit the origins converted from their XCM `Transact`.
+pub struct EnsureSiblingPara<O = RuntimeOrigin>(PhantomData<O>);
+
+impl<O> EnsureOrigin<O> for EnsureSiblingPara<O>
+where
+	O: Clone + Into<Result<frame_system::RawOrigin<u64>, O>> + From<frame_system::RawOrigin<u64>>,
+{
+	type Success = ParaId;
+
+	fn try_origin(o: O) -> Result<ParaId, O> {
+		match o.clone().into() {
+			Ok(frame_system::RawOrigin::Signed(who)) if who >= PARA_ACCOUNT_OFFSET =>
+				Ok(ParaId::from((who - PARA_ACCOUNT_OFFSET) as u32)),
+			_ => Err(o),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<O, ()> {
+		Ok(frame_system::RawOrigin::Signed(PARA_ACCOUNT_OFFSET + 2_000).into())
+	}
+}
+
+impl pallet_custom::Config for Test {
+	type CounterValue = u64;
+	type CounterMaxValue = CounterMaxValue;
+	type CounterMinValue = CounterMinValue;
+	type MaxOpsPerCall = MaxOpsPerCall;
+	type MaxHistory = MaxHistory;
+	type MaxLeaderboard = MaxLeaderboard;
+	type MaxNameLength = MaxNameLength;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type CounterDeposit = CounterDeposit;
+	type InteractionDeposit = InteractionDeposit;
+	type MaxInteractionsPerPeriod = MaxInteractionsPerPeriod;
+	type RatePeriod = RatePeriod;
+	type ResetPeriod = ResetPeriod;
+	type ResetInteractions = ResetInteractions;
+	type IncrementOrigin = MutationOrigin;
+	type DecrementOrigin = MutationOrigin;
+	type AdminOrigin = EnsureRoot<u64>;
+	type PalletsOrigin = OriginCaller;
+	type Scheduler = Scheduler;
+	type Preimages = Preimage;
+	type AuthorityId = TestAuthorityId;
+	type OffchainInterval = OffchainInterval;
+	type UnsignedPriority = UnsignedPriority;
+	type UnsignedLongevity = UnsignedLongevity;
+	type MaxUnsignedPerBlock = MaxUnsignedPerBlock;
+	type XcmOrigin = EnsureSiblingPara;
+	type WeightInfo = ();
+}
+
+/// Run blocks until `n`, dispatching the calls scheduled in between.
+pub fn run_to_block(n: u64) {
+	System::run_to_block::<AllPalletsWithSystem>(n);
+}
+
+/// The balance every endowed account starts with.
+pub const INITIAL_BALANCE: u64 = 100;
+
+/// Build genesis storage for the test runtime, starting at block 1 so events are recorded.
+///
+/// Accounts `1` to `4` are endowed with [`INITIAL_BALANCE`].
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	new_test_ext_with_genesis(Default::default())
+}
+
+/// Same as [`new_test_ext`], with `genesis` as the initial state of the pallet.
+pub fn new_test_ext_with_genesis(
+	genesis: pallet_custom::GenesisConfig<Test>,
+) -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: (1..=4).map(|who| (who, INITIAL_BALANCE)).collect(),
+		..Default::default()
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	genesis.assimilate_storage(&mut t).unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+/// Same as [`new_test_ext`], with the off-chain worker and transaction pool extensions.
+///
+/// Returns the state of the off-chain extension, to expect HTTP requests, and of the pool, to
+/// inspect the submitted transactions.
+pub fn new_offchain_test_ext(
+) -> (sp_io::TestExternalities, Arc<RwLock<OffchainState>>, Arc<RwLock<PoolState>>) {
+	let (offchain, offchain_state) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let mut ext = new_test_ext();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	(ext, offchain_state, pool_state)
+}
@@ -0,0 +1,162 @@
+//! A test runtime with a signed `i64` counter, bounded below by a negative
+//! [`CounterMinValue`].
+
+use super::{
+	CounterDeposit, EnsureSiblingPara, InteractionDeposit, MaxHistory, MaxInteractionsPerPeriod,
+	MaxLeaderboard, MaxNameLength, MaxOpsPerCall, MaxUnsignedPerBlock, MaximumSchedulerWeight,
+	OffchainInterval, RatePeriod, ResetInteractions, ResetPeriod, TestAuthorityId,
+	UnsignedLongevity, UnsignedPriority, INITIAL_BALANCE,
+};
+use crate::{self as pallet_custom};
+use codec::Encode;
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstU32, EqualPrivilegeOnly},
+};
+use frame_system::{
+	offchain::{AppCrypto, CreateSignedTransaction, CreateTransactionBase, SigningTypes},
+	EnsureRoot, EnsureSigned,
+};
+use sp_runtime::{
+	generic::UncheckedExtrinsic,
+	testing::{TestSignature, UintAuthorityId},
+	BuildStorage,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+		Balances: pallet_balances,
+		Preimage: pallet_preimage,
+		Scheduler: pallet_scheduler,
+		CustomPallet: pallet_custom,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+	type RuntimeHoldReason = RuntimeHoldReason;
+}
+
+impl pallet_preimage::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Currency = ();
+	type ManagerOrigin = EnsureRoot<u64>;
+	type Consideration = ();
+}
+
+impl pallet_scheduler::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type PalletsOrigin = OriginCaller;
+	type RuntimeCall = RuntimeCall;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type ScheduleOrigin = EnsureRoot<u64>;
+	type OriginPrivilegeCmp = EqualPrivilegeOnly;
+	type MaxScheduledPerBlock = ConstU32<10>;
+	type WeightInfo = ();
+	type Preimages = Preimage;
+	type BlockNumberProvider = System;
+}
+
+/// The transactions submitted by the off-chain worker, signed by a [`UintAuthorityId`].
+pub type Extrinsic = UncheckedExtrinsic<u64, RuntimeCall, TestSignature, ()>;
+
+impl SigningTypes for Test {
+	type Public = UintAuthorityId;
+	type Signature = TestSignature;
+}
+
+impl<LocalCall> CreateTransactionBase<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type Extrinsic = Extrinsic;
+	type RuntimeCall = RuntimeCall;
+}
+
+impl<LocalCall> CreateSignedTransaction<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_signed_transaction<C: AppCrypto<Self::Public, Self::Signature>>(
+		call: RuntimeCall,
+		public: UintAuthorityId,
+		account: u64,
+		nonce: Self::Nonce,
+	) -> Option<Extrinsic> {
+		let signature = C::sign(&(&call, nonce).encode(), public)?;
+		Some(Extrinsic::new_signed(call, account, signature, ()))
+	}
+}
+
+parameter_types! {
+	pub const CounterMaxValue: i64 = 10;
+	pub static CounterMinValue: i64 = -10;
+}
+
+impl pallet_custom::Config for Test {
+	type CounterValue = i64;
+	type CounterMaxValue = CounterMaxValue;
+	type CounterMinValue = CounterMinValue;
+	type MaxOpsPerCall = MaxOpsPerCall;
+	type MaxHistory = MaxHistory;
+	type MaxLeaderboard = MaxLeaderboard;
+	type MaxNameLength = MaxNameLength;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type CounterDeposit = CounterDeposit;
+	type InteractionDeposit = InteractionDeposit;
+	type MaxInteractionsPerPeriod = MaxInteractionsPerPeriod;
+	type RatePeriod = RatePeriod;
+	type ResetPeriod = ResetPeriod;
+	type ResetInteractions = ResetInteractions;
+	type IncrementOrigin = EnsureSigned<u64>;
+	type DecrementOrigin = EnsureSigned<u64>;
+	type AdminOrigin = EnsureRoot<u64>;
+	type PalletsOrigin = OriginCaller;
+	type Scheduler = Scheduler;
+	type Preimages = Preimage;
+	type AuthorityId = TestAuthorityId;
+	type OffchainInterval = OffchainInterval;
+	type UnsignedPriority = UnsignedPriority;
+	type UnsignedLongevity = UnsignedLongevity;
+	type MaxUnsignedPerBlock = MaxUnsignedPerBlock;
+	type XcmOrigin = EnsureSiblingPara<RuntimeOrigin>;
+	type WeightInfo = ();
+}
+
+/// Build genesis storage for the signed test runtime, starting at block 1 so events are
+/// recorded.
+///
+/// Accounts `1` to `4` are endowed with [`INITIAL_BALANCE`].
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	new_test_ext_with_genesis(Default::default())
+}
+
+/// Same as [`new_test_ext`], with `genesis` as the initial state of the pallet.
+pub fn new_test_ext_with_genesis(
+	genesis: pallet_custom::GenesisConfig<Test>,
+) -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: (1..=4).map(|who| (who, INITIAL_BALANCE)).collect(),
+		..Default::default()
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	genesis.assimilate_storage(&mut t).unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,2599 @@
+use crate::{
+	extensions::ValidityError,
+	mock::*,
+	traits::{CounterInspect, CounterMutate},
+	AllowList, AuthorizedKeys, CheckCounterBounds, CounterHistory, CounterInfo, CounterOp,
+	CounterValue, Counters, CountersByAccount, Error, Event, GenesisConfig, HoldReason,
+	IncrementPayload, InteractionRecord, MaxValueOverride, NextCounterId, ParaId, ParaInteractions,
+	PendingCounterOwners, RateLimits, TopInteractors, UnsignedNonces, UserInteractions, WeightInfo,
+};
+use codec::{Decode, Encode};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::GetDispatchInfo,
+	traits::{
+		fungible::{Inspect, InspectHold},
+		Hooks,
+	},
+	weights::Weight,
+	BoundedVec,
+};
+use sp_core::{
+	offchain::{
+		testing::{OffchainState, PendingRequest},
+		StorageKind,
+	},
+	H256,
+};
+use sp_runtime::{
+	generic::Preamble,
+	testing::{TestSignature, UintAuthorityId},
+	traits::{DispatchTransaction, ValidateUnsigned},
+	transaction_validity::{
+		InvalidTransaction, TransactionSource, TransactionValidityError, ValidTransaction,
+	},
+	DispatchError, TokenError,
+};
+
+mod signed;
+
+fn name(name: &[u8]) -> BoundedVec<u8, MaxNameLength> {
+	name.to_vec().try_into().unwrap()
+}
+
+fn interactions(who: u64) -> Option<u32> {
+	UserInteractions::<Test>::get(who).map(|record| record.count)
+}
+
+fn ops(operations: &[CounterOp<u64>]) -> BoundedVec<CounterOp<u64>, MaxOpsPerCall> {
+	operations.to_vec().try_into().unwrap()
+}
+
+fn history() -> Vec<(u64, u64)> {
+	CounterHistory::<Test>::get().into_inner()
+}
+
+fn leaderboard() -> Vec<(u64, u32)> {
+	TopInteractors::<Test>::get().into_inner()
+}
+
+/// The accounts and ranks of the `LeaderboardChanged` events deposited so far.
+fn leaderboard_events() -> Vec<(u64, Option<u32>)> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::CustomPallet(Event::LeaderboardChanged { who, rank }) =>
+				Some((who, rank)),
+			_ => None,
+		})
+		.collect()
+}
+
+/// The URL of the external source in the off-chain tests.
+const EXTERNAL_URL: &str = "https://example.com/counter";
+
+/// Point the off-chain worker at [`EXTERNAL_URL`].
+fn set_external_url() {
+	sp_io::offchain::local_storage_set(
+		StorageKind::PERSISTENT,
+		crate::EXTERNAL_VALUE_URL_KEY,
+		EXTERNAL_URL.as_bytes(),
+	);
+}
+
+/// Put `who` in the local keystore and on the [`AuthorizedKeys`].
+fn set_authorized_key(who: u64) {
+	UintAuthorityId::set_all_keys([who]);
+	AuthorizedKeys::<Test>::insert(who, ());
+}
+
+/// Have the external source answer the next request with `body`.
+fn expect_external_value(state: &mut OffchainState, body: &[u8]) {
+	state.expect_request(PendingRequest {
+		method: "GET".into(),
+		uri: EXTERNAL_URL.into(),
+		response: Some(body.to_vec()),
+		sent: true,
+		..Default::default()
+	});
+}
+
+/// An increment of the counter by `amount` by `who`, for the test chain.
+fn increment_payload(
+	who: u64,
+	amount: u64,
+	nonce: u32,
+) -> IncrementPayload<UintAuthorityId, u64, H256> {
+	IncrementPayload {
+		public: UintAuthorityId(who),
+		amount,
+		nonce,
+		genesis_hash: System::block_hash(0),
+	}
+}
+
+/// An `increment_unsigned` call incrementing the counter by `amount`, signed by `who`.
+fn increment_unsigned(who: u64, amount: u64, nonce: u32) -> crate::Call<Test> {
+	let payload = increment_payload(who, amount, nonce);
+	let signature = TestSignature(who, payload.encode());
+	crate::Call::increment_unsigned { payload, signature }
+}
+
+/// Validate `call` as the transaction pool would.
+fn validate(call: &crate::Call<Test>) -> sp_runtime::transaction_validity::TransactionValidity {
+	CustomPallet::validate_unsigned(TransactionSource::External, call)
+}
+
+/// Dispatch `call` as the block builder would, after its final validation.
+fn dispatch_unsigned(call: crate::Call<Test>) -> sp_runtime::DispatchResult {
+	CustomPallet::pre_dispatch(&call).map_err(|error| DispatchError::Other(error.into()))?;
+	let crate::Call::increment_unsigned { payload, signature } = call else { unreachable!() };
+	CustomPallet::increment_unsigned(RuntimeOrigin::none(), payload, signature)
+}
+
+/// Validate the signed transaction `call` against [`CheckCounterBounds`].
+fn check_bounds(call: crate::Call<Test>) -> Result<(), TransactionValidityError> {
+	let call = RuntimeCall::CustomPallet(call);
+	CheckCounterBounds::<Test>::new()
+		.validate_only(
+			RuntimeOrigin::signed(1),
+			&call,
+			&call.get_dispatch_info(),
+			0,
+			TransactionSource::External,
+			0,
+		)
+		.map(|_| ())
+}
+
+/// The XCM origin of the sibling parachain `id`.
+fn para(id: u32) -> RuntimeOrigin {
+	RuntimeOrigin::signed(PARA_ACCOUNT_OFFSET + id as u64)
+}
+
+fn para_interactions(id: u32) -> Option<u32> {
+	ParaInteractions::<Test>::get(ParaId::from(id)).map(|record| record.count)
+}
+
+fn held(reason: HoldReason, who: u64) -> u64 {
+	Balances::balance_on_hold(&reason.into(), &who)
+}
+
+#[test]
+fn set_counter_value_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), 5));
+
+		assert_eq!(CounterValue::<Test>::get(), Some(5));
+		System::assert_last_event(Event::CounterValueSet { counter_value: 5 }.into());
+	});
+}
+
+#[test]
+fn set_counter_value_accepts_max_value() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), CounterMaxValue::get()));
+
+		assert_eq!(CounterValue::<Test>::get(), Some(CounterMaxValue::get()));
+	});
+}
+
+#[test]
+fn set_counter_value_requires_root() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CustomPallet::set_counter_value(RuntimeOrigin::signed(1), 5),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			CustomPallet::set_counter_value(RuntimeOrigin::none(), 5),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_counter_value_rejects_values_above_max() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CustomPallet::set_counter_value(RuntimeOrigin::root(), CounterMaxValue::get() + 1),
+			Error::<Test>::CounterValueExceedsMax
+		);
+	});
+}
+
+#[test]
+fn increment_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 3));
+		assert_eq!(CounterValue::<Test>::get(), Some(3));
+		assert_eq!(interactions(1), Some(1));
+		System::assert_last_event(
+			Event::CounterIncremented { counter_value: 3, who: 1, incremented_amount: 3 }.into(),
+		);
+
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 2));
+		assert_eq!(CounterValue::<Test>::get(), Some(5));
+		assert_eq!(interactions(1), Some(2));
+	});
+}
+
+#[test]
+fn increment_requires_signed_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(CustomPallet::increment(RuntimeOrigin::root(), 1), DispatchError::BadOrigin);
+		assert_noop!(CustomPallet::increment(RuntimeOrigin::none(), 1), DispatchError::BadOrigin);
+	});
+}
+
+#[test]
+fn increment_fails_on_overflow() {
+	new_test_ext().execute_with(|| {
+		CounterValue::<Test>::put(u64::MAX);
+
+		assert_noop!(
+			CustomPallet::increment(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::CounterOverflow
+		);
+	});
+}
+
+#[test]
+fn increment_fails_above_max() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), 8));
+
+		assert_noop!(
+			CustomPallet::increment(RuntimeOrigin::signed(1), 3),
+			Error::<Test>::CounterValueExceedsMax
+		);
+		assert_eq!(interactions(1), None);
+	});
+}
+
+#[test]
+fn decrement_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), 8));
+
+		assert_ok!(CustomPallet::decrement(RuntimeOrigin::signed(2), 3));
+		assert_eq!(CounterValue::<Test>::get(), Some(5));
+		assert_eq!(interactions(2), Some(1));
+		System::assert_last_event(
+			Event::CounterDecremented { counter_value: 5, who: 2, decremented_amount: 3 }.into(),
+		);
+
+		assert_ok!(CustomPallet::decrement(RuntimeOrigin::signed(2), 5));
+		assert_eq!(CounterValue::<Test>::get(), Some(0));
+		assert_eq!(interactions(2), Some(2));
+	});
+}
+
+#[test]
+fn decrement_requires_signed_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(CustomPallet::decrement(RuntimeOrigin::root(), 1), DispatchError::BadOrigin);
+		assert_noop!(CustomPallet::decrement(RuntimeOrigin::none(), 1), DispatchError::BadOrigin);
+	});
+}
+
+#[test]
+fn decrement_fails_below_zero() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CustomPallet::decrement(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::CounterValueBelowZero
+		);
+
+		assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), 2));
+		assert_noop!(
+			CustomPallet::decrement(RuntimeOrigin::signed(1), 3),
+			Error::<Test>::CounterValueBelowZero
+		);
+	});
+}
+
+#[test]
+fn interactions_are_tracked_per_account() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 4));
+		assert_ok!(CustomPallet::decrement(RuntimeOrigin::signed(1), 1));
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(2), 1));
+
+		assert_eq!(interactions(1), Some(2));
+		assert_eq!(interactions(2), Some(1));
+		assert_eq!(interactions(3), None);
+		assert_eq!(CounterValue::<Test>::get(), Some(4));
+	});
+}
+
+#[test]
+fn set_counter_value_does_not_record_interactions() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), 5));
+
+		assert_eq!(UserInteractions::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn increment_fails_on_user_interaction_overflow() {
+	new_test_ext().execute_with(|| {
+		UserInteractions::<Test>::insert(1, InteractionRecord { count: u32::MAX, last_block: 1 });
+
+		assert_noop!(
+			CustomPallet::increment(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::UserInteractionOverflow
+		);
+		assert_eq!(CounterValue::<Test>::get(), None);
+	});
+}
+
+#[test]
+fn decrement_fails_on_user_interaction_overflow() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), 5));
+		UserInteractions::<Test>::insert(1, InteractionRecord { count: u32::MAX, last_block: 1 });
+
+		assert_noop!(
+			CustomPallet::decrement(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::UserInteractionOverflow
+		);
+		assert_eq!(CounterValue::<Test>::get(), Some(5));
+	});
+}
+
+#[test]
+fn increment_own_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::increment_own(RuntimeOrigin::signed(1), 4));
+		assert_ok!(CustomPallet::increment_own(RuntimeOrigin::signed(1), 3));
+
+		assert_eq!(CountersByAccount::<Test>::get(1), Some(7));
+		System::assert_last_event(
+			Event::OwnCounterIncremented { who: 1, counter_value: 7, incremented_amount: 3 }.into(),
+		);
+	});
+}
+
+#[test]
+fn own_counters_are_independent() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::increment_own(RuntimeOrigin::signed(1), 4));
+		assert_ok!(CustomPallet::increment_own(RuntimeOrigin::signed(2), 9));
+
+		assert_eq!(CountersByAccount::<Test>::get(1), Some(4));
+		assert_eq!(CountersByAccount::<Test>::get(2), Some(9));
+		assert_eq!(CounterValue::<Test>::get(), None);
+		assert_eq!(UserInteractions::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn increment_own_respects_max() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::increment_own(RuntimeOrigin::signed(1), CounterMaxValue::get()));
+
+		assert_noop!(
+			CustomPallet::increment_own(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::CounterValueExceedsMax
+		);
+	});
+}
+
+#[test]
+fn increment_own_fails_on_overflow() {
+	new_test_ext().execute_with(|| {
+		CountersByAccount::<Test>::insert(1, u64::MAX);
+
+		assert_noop!(
+			CustomPallet::increment_own(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::CounterOverflow
+		);
+	});
+}
+
+#[test]
+fn decrement_own_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::increment_own(RuntimeOrigin::signed(1), 5));
+		assert_ok!(CustomPallet::decrement_own(RuntimeOrigin::signed(1), 2));
+
+		assert_eq!(CountersByAccount::<Test>::get(1), Some(3));
+		System::assert_last_event(
+			Event::OwnCounterDecremented { who: 1, counter_value: 3, decremented_amount: 2 }.into(),
+		);
+	});
+}
+
+#[test]
+fn decrement_own_fails_below_zero() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CustomPallet::decrement_own(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::CounterValueBelowZero
+		);
+	});
+}
+
+#[test]
+fn reset_own_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::increment_own(RuntimeOrigin::signed(1), 5));
+		assert_ok!(CustomPallet::increment_own(RuntimeOrigin::signed(2), 5));
+
+		assert_ok!(CustomPallet::reset_own(RuntimeOrigin::signed(1)));
+
+		assert_eq!(CountersByAccount::<Test>::get(1), None);
+		assert_eq!(CountersByAccount::<Test>::get(2), Some(5));
+		System::assert_last_event(Event::OwnCounterReset { who: 1 }.into());
+	});
+}
+
+#[test]
+fn own_counter_calls_require_signed_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CustomPallet::increment_own(RuntimeOrigin::root(), 1),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			CustomPallet::decrement_own(RuntimeOrigin::root(), 1),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(CustomPallet::reset_own(RuntimeOrigin::none()), DispatchError::BadOrigin);
+	});
+}
+
+#[test]
+fn create_counter_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+
+		assert_ok!(CustomPallet::create_counter(RuntimeOrigin::signed(1), name(b"quota"), 100, 7));
+
+		assert_eq!(
+			Counters::<Test>::get(0),
+			Some(CounterInfo {
+				owner: 1,
+				name: name(b"quota"),
+				value: 7,
+				max: 100,
+				created_at: 5,
+				deposit: CounterDeposit::get(),
+			})
+		);
+		assert_eq!(NextCounterId::<Test>::get(), 1);
+		System::assert_last_event(
+			Event::CounterCreated { id: 0, owner: 1, max: 100, initial: 7 }.into(),
+		);
+
+		assert_ok!(CustomPallet::create_counter(RuntimeOrigin::signed(2), name(b"score"), 5, 0));
+		assert_eq!(Counters::<Test>::get(1).map(|info| info.owner), Some(2));
+		assert_eq!(NextCounterId::<Test>::get(), 2);
+	});
+}
+
+#[test]
+fn create_counter_is_not_bound_by_global_max() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::create_counter(
+			RuntimeOrigin::signed(1),
+			name(b"big"),
+			1_000,
+			CounterMaxValue::get() + 1
+		));
+	});
+}
+
+#[test]
+fn create_counter_rejects_initial_above_max() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CustomPallet::create_counter(RuntimeOrigin::signed(1), name(b"quota"), 5, 6),
+			Error::<Test>::CounterValueExceedsMax
+		);
+	});
+}
+
+#[test]
+fn create_counter_fails_when_ids_are_exhausted() {
+	new_test_ext().execute_with(|| {
+		NextCounterId::<Test>::put(u32::MAX);
+
+		assert_noop!(
+			CustomPallet::create_counter(RuntimeOrigin::signed(1), name(b"quota"), 5, 0),
+			Error::<Test>::CounterIdOverflow
+		);
+	});
+}
+
+#[test]
+fn increment_counter_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::create_counter(RuntimeOrigin::signed(1), name(b"quota"), 20, 0));
+
+		assert_ok!(CustomPallet::increment_counter(RuntimeOrigin::signed(1), 0, 15));
+
+		assert_eq!(Counters::<Test>::get(0).map(|info| info.value), Some(15));
+		System::assert_last_event(
+			Event::RegistryCounterIncremented { id: 0, counter_value: 15, incremented_amount: 15 }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn increment_counter_respects_counter_max() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::create_counter(RuntimeOrigin::signed(1), name(b"quota"), 20, 18));
+
+		assert_noop!(
+			CustomPallet::increment_counter(RuntimeOrigin::signed(1), 0, 3),
+			Error::<Test>::CounterValueExceedsMax
+		);
+	});
+}
+
+#[test]
+fn increment_counter_fails_on_overflow() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::create_counter(
+			RuntimeOrigin::signed(1),
+			name(b"quota"),
+			u64::MAX,
+			u64::MAX
+		));
+
+		assert_noop!(
+			CustomPallet::increment_counter(RuntimeOrigin::signed(1), 0, 1),
+			Error::<Test>::CounterOverflow
+		);
+	});
+}
+
+#[test]
+fn decrement_counter_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::create_counter(RuntimeOrigin::signed(1), name(b"quota"), 20, 12));
+
+		assert_ok!(CustomPallet::decrement_counter(RuntimeOrigin::signed(1), 0, 5));
+
+		assert_eq!(Counters::<Test>::get(0).map(|info| info.value), Some(7));
+		System::assert_last_event(
+			Event::RegistryCounterDecremented { id: 0, counter_value: 7, decremented_amount: 5 }
+				.into(),
+		);
+
+		assert_noop!(
+			CustomPallet::decrement_counter(RuntimeOrigin::signed(1), 0, 8),
+			Error::<Test>::CounterValueBelowZero
+		);
+	});
+}
+
+#[test]
+fn registry_counters_are_independent() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::create_counter(RuntimeOrigin::signed(1), name(b"a"), 20, 0));
+		assert_ok!(CustomPallet::create_counter(RuntimeOrigin::signed(1), name(b"b"), 20, 0));
+
+		assert_ok!(CustomPallet::increment_counter(RuntimeOrigin::signed(1), 1, 4));
+
+		assert_eq!(Counters::<Test>::get(0).map(|info| info.value), Some(0));
+		assert_eq!(Counters::<Test>::get(1).map(|info| info.value), Some(4));
+		assert_eq!(CounterValue::<Test>::get(), None);
+	});
+}
+
+#[test]
+fn registry_calls_fail_for_unknown_counter() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CustomPallet::increment_counter(RuntimeOrigin::signed(1), 0, 1),
+			Error::<Test>::CounterNotFound
+		);
+		assert_noop!(
+			CustomPallet::decrement_counter(RuntimeOrigin::signed(1), 0, 1),
+			Error::<Test>::CounterNotFound
+		);
+		assert_noop!(
+			CustomPallet::transfer_counter_ownership(RuntimeOrigin::signed(1), 0, 2),
+			Error::<Test>::CounterNotFound
+		);
+		assert_noop!(
+			CustomPallet::accept_counter_ownership(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::NotPendingOwner
+		);
+		assert_noop!(
+			CustomPallet::destroy_counter(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::CounterNotFound
+		);
+	});
+}
+
+#[test]
+fn registry_calls_require_ownership() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::create_counter(RuntimeOrigin::signed(1), name(b"quota"), 20, 5));
+
+		assert_noop!(
+			CustomPallet::increment_counter(RuntimeOrigin::signed(2), 0, 1),
+			Error::<Test>::NotCounterOwner
+		);
+		assert_noop!(
+			CustomPallet::decrement_counter(RuntimeOrigin::signed(2), 0, 1),
+			Error::<Test>::NotCounterOwner
+		);
+		assert_noop!(
+			CustomPallet::transfer_counter_ownership(RuntimeOrigin::signed(2), 0, 2),
+			Error::<Test>::NotCounterOwner
+		);
+		assert_noop!(
+			CustomPallet::destroy_counter(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::NotCounterOwner
+		);
+	});
+}
+
+#[test]
+fn transfer_counter_ownership_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::create_counter(RuntimeOrigin::signed(1), name(b"quota"), 20, 5));
+
+		assert_ok!(CustomPallet::transfer_counter_ownership(RuntimeOrigin::signed(1), 0, 2));
+		assert_eq!(PendingCounterOwners::<Test>::get(0), Some(2));
+		System::assert_last_event(Event::CounterOwnershipOffered { id: 0, from: 1, to: 2 }.into());
+
+		// The ownership only changes once accepted.
+		assert_eq!(Counters::<Test>::get(0).map(|info| info.owner), Some(1));
+		assert_ok!(CustomPallet::accept_counter_ownership(RuntimeOrigin::signed(2), 0));
+
+		assert_eq!(Counters::<Test>::get(0).map(|info| info.owner), Some(2));
+		assert_eq!(PendingCounterOwners::<Test>::get(0), None);
+		System::assert_last_event(
+			Event::CounterOwnershipTransferred { id: 0, from: 1, to: 2 }.into(),
+		);
+
+		assert_noop!(
+			CustomPallet::increment_counter(RuntimeOrigin::signed(1), 0, 1),
+			Error::<Test>::NotCounterOwner
+		);
+		assert_ok!(CustomPallet::increment_counter(RuntimeOrigin::signed(2), 0, 1));
+	});
+}
+
+#[test]
+fn destroy_counter_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::create_counter(RuntimeOrigin::signed(1), name(b"quota"), 20, 5));
+
+		assert_ok!(CustomPallet::destroy_counter(RuntimeOrigin::signed(1), 0));
+
+		assert_eq!(Counters::<Test>::get(0), None);
+		System::assert_last_event(Event::CounterDestroyed { id: 0 }.into());
+
+		// Identifiers are never reused.
+		assert_ok!(CustomPallet::create_counter(RuntimeOrigin::signed(1), name(b"quota"), 20, 5));
+		assert!(Counters::<Test>::get(1).is_some());
+	});
+}
+
+#[test]
+fn first_interaction_holds_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 1));
+		assert_eq!(held(HoldReason::InteractionDeposit, 1), InteractionDeposit::get());
+
+		assert_ok!(CustomPallet::decrement(RuntimeOrigin::signed(1), 1));
+		assert_eq!(held(HoldReason::InteractionDeposit, 1), InteractionDeposit::get());
+		assert_eq!(Balances::balance(&1), INITIAL_BALANCE - InteractionDeposit::get());
+	});
+}
+
+#[test]
+fn first_interaction_requires_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CustomPallet::increment(RuntimeOrigin::signed(9), 1),
+			TokenError::FundsUnavailable
+		);
+		assert_eq!(interactions(9), None);
+	});
+}
+
+#[test]
+fn clear_interactions_releases_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 1));
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 1));
+		run_to_block(1 + RatePeriod::get());
+
+		assert_ok!(CustomPallet::clear_interactions(RuntimeOrigin::signed(1)));
+
+		assert_eq!(interactions(1), None);
+		assert_eq!(held(HoldReason::InteractionDeposit, 1), 0);
+		assert_eq!(Balances::balance(&1), INITIAL_BALANCE);
+		assert_eq!(CounterValue::<Test>::get(), Some(2));
+		System::assert_last_event(Event::InteractionsCleared { who: 1 }.into());
+
+		// A new record holds a new deposit.
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 1));
+		assert_eq!(interactions(1), Some(1));
+		assert_eq!(held(HoldReason::InteractionDeposit, 1), InteractionDeposit::get());
+	});
+}
+
+#[test]
+fn clear_interactions_removes_the_rate_limit() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..MaxInteractionsPerPeriod::get() {
+			assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 1));
+		}
+
+		// Clearing does not lift the rate limit of the current period.
+		assert_noop!(
+			CustomPallet::clear_interactions(RuntimeOrigin::signed(1)),
+			Error::<Test>::RatePeriodNotOver
+		);
+
+		run_to_block(1 + RatePeriod::get());
+		assert_ok!(CustomPallet::clear_interactions(RuntimeOrigin::signed(1)));
+		assert_eq!(RateLimits::<Test>::get(1), None);
+		assert_eq!(interactions(1), None);
+	});
+}
+
+#[test]
+fn clear_interactions_requires_a_record() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CustomPallet::clear_interactions(RuntimeOrigin::signed(1)),
+			Error::<Test>::NoInteractions
+		);
+	});
+}
+
+#[test]
+fn create_counter_holds_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::create_counter(RuntimeOrigin::signed(1), name(b"a"), 20, 0));
+		assert_ok!(CustomPallet::create_counter(RuntimeOrigin::signed(1), name(b"b"), 20, 0));
+
+		assert_eq!(held(HoldReason::CounterDeposit, 1), 2 * CounterDeposit::get());
+	});
+}
+
+#[test]
+fn create_counter_requires_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CustomPallet::create_counter(RuntimeOrigin::signed(9), name(b"quota"), 20, 0),
+			TokenError::FundsUnavailable
+		);
+		assert_eq!(NextCounterId::<Test>::get(), 0);
+	});
+}
+
+#[test]
+fn destroy_counter_releases_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::create_counter(RuntimeOrigin::signed(1), name(b"quota"), 20, 0));
+
+		assert_ok!(CustomPallet::destroy_counter(RuntimeOrigin::signed(1), 0));
+
+		assert_eq!(held(HoldReason::CounterDeposit, 1), 0);
+		assert_eq!(Balances::balance(&1), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn transfer_counter_ownership_holds_nothing_from_new_owner() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::create_counter(RuntimeOrigin::signed(1), name(b"quota"), 20, 0));
+
+		assert_ok!(CustomPallet::transfer_counter_ownership(RuntimeOrigin::signed(1), 0, 2));
+
+		assert_eq!(held(HoldReason::CounterDeposit, 1), CounterDeposit::get());
+		assert_eq!(held(HoldReason::CounterDeposit, 2), 0);
+		assert_eq!(Balances::balance(&2), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn accept_counter_ownership_moves_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::create_counter(RuntimeOrigin::signed(1), name(b"quota"), 20, 0));
+		assert_ok!(CustomPallet::transfer_counter_ownership(RuntimeOrigin::signed(1), 0, 2));
+
+		assert_ok!(CustomPallet::accept_counter_ownership(RuntimeOrigin::signed(2), 0));
+
+		assert_eq!(held(HoldReason::CounterDeposit, 1), 0);
+		assert_eq!(held(HoldReason::CounterDeposit, 2), CounterDeposit::get());
+
+		assert_ok!(CustomPallet::destroy_counter(RuntimeOrigin::signed(2), 0));
+		assert_eq!(held(HoldReason::CounterDeposit, 2), 0);
+	});
+}
+
+#[test]
+fn accept_counter_ownership_requires_deposit_from_new_owner() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::create_counter(RuntimeOrigin::signed(1), name(b"quota"), 20, 0));
+		assert_ok!(CustomPallet::transfer_counter_ownership(RuntimeOrigin::signed(1), 0, 9));
+
+		assert_noop!(
+			CustomPallet::accept_counter_ownership(RuntimeOrigin::signed(9), 0),
+			TokenError::FundsUnavailable
+		);
+		assert_eq!(held(HoldReason::CounterDeposit, 1), CounterDeposit::get());
+	});
+}
+
+#[test]
+fn accept_counter_ownership_requires_the_offer() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::create_counter(RuntimeOrigin::signed(1), name(b"quota"), 20, 0));
+		assert_noop!(
+			CustomPallet::accept_counter_ownership(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::NotPendingOwner
+		);
+
+		assert_ok!(CustomPallet::transfer_counter_ownership(RuntimeOrigin::signed(1), 0, 2));
+		assert_noop!(
+			CustomPallet::accept_counter_ownership(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::NotPendingOwner
+		);
+
+		// A new offer replaces the previous one.
+		assert_ok!(CustomPallet::transfer_counter_ownership(RuntimeOrigin::signed(1), 0, 3));
+		assert_noop!(
+			CustomPallet::accept_counter_ownership(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::NotPendingOwner
+		);
+		assert_ok!(CustomPallet::accept_counter_ownership(RuntimeOrigin::signed(3), 0));
+	});
+}
+
+#[test]
+fn destroy_counter_withdraws_the_offer() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::create_counter(RuntimeOrigin::signed(1), name(b"quota"), 20, 0));
+		assert_ok!(CustomPallet::transfer_counter_ownership(RuntimeOrigin::signed(1), 0, 2));
+
+		assert_ok!(CustomPallet::destroy_counter(RuntimeOrigin::signed(1), 0));
+
+		assert_eq!(PendingCounterOwners::<Test>::get(0), None);
+		assert_noop!(
+			CustomPallet::accept_counter_ownership(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::NotPendingOwner
+		);
+	});
+}
+
+#[test]
+fn own_counter_holds_deposit_until_reset() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::increment_own(RuntimeOrigin::signed(1), 2));
+		assert_ok!(CustomPallet::increment_own(RuntimeOrigin::signed(1), 2));
+		assert_eq!(held(HoldReason::OwnCounterDeposit, 1), CounterDeposit::get());
+
+		assert_ok!(CustomPallet::reset_own(RuntimeOrigin::signed(1)));
+
+		assert_eq!(held(HoldReason::OwnCounterDeposit, 1), 0);
+		assert_eq!(Balances::balance(&1), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn own_counter_requires_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CustomPallet::increment_own(RuntimeOrigin::signed(9), 1),
+			TokenError::FundsUnavailable
+		);
+		assert_noop!(
+			CustomPallet::decrement_own(RuntimeOrigin::signed(9), 0),
+			TokenError::FundsUnavailable
+		);
+		assert_eq!(CountersByAccount::<Test>::get(9), None);
+	});
+}
+
+#[test]
+fn reset_own_works_for_counters_without_deposit() {
+	new_test_ext().execute_with(|| {
+		CountersByAccount::<Test>::insert(1, 5);
+
+		assert_ok!(CustomPallet::reset_own(RuntimeOrigin::signed(1)));
+
+		assert_eq!(CountersByAccount::<Test>::get(1), None);
+		assert_eq!(Balances::balance(&1), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn add_to_allow_list_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::add_to_allow_list(RuntimeOrigin::root(), 1));
+
+		assert!(AllowList::<Test>::contains_key(1));
+		System::assert_last_event(Event::AddedToAllowList { who: 1 }.into());
+
+		assert_noop!(
+			CustomPallet::add_to_allow_list(RuntimeOrigin::root(), 1),
+			Error::<Test>::AlreadyAllowListed
+		);
+	});
+}
+
+#[test]
+fn remove_from_allow_list_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::add_to_allow_list(RuntimeOrigin::root(), 1));
+
+		assert_ok!(CustomPallet::remove_from_allow_list(RuntimeOrigin::root(), 1));
+
+		assert!(!AllowList::<Test>::contains_key(1));
+		System::assert_last_event(Event::RemovedFromAllowList { who: 1 }.into());
+
+		assert_noop!(
+			CustomPallet::remove_from_allow_list(RuntimeOrigin::root(), 1),
+			Error::<Test>::NotAllowListed
+		);
+	});
+}
+
+#[test]
+fn allow_list_is_managed_by_admin_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CustomPallet::add_to_allow_list(RuntimeOrigin::signed(1), 1),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(CustomPallet::add_to_allow_list(RuntimeOrigin::root(), 1));
+		assert_noop!(
+			CustomPallet::remove_from_allow_list(RuntimeOrigin::signed(1), 1),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn restricted_mutations_require_allow_listed_account() {
+	new_test_ext().execute_with(|| {
+		RestrictMutations::set(true);
+		assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), 5));
+
+		assert_noop!(
+			CustomPallet::increment(RuntimeOrigin::signed(1), 1),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			CustomPallet::decrement(RuntimeOrigin::signed(1), 1),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(CustomPallet::add_to_allow_list(RuntimeOrigin::root(), 1));
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 1));
+		assert_ok!(CustomPallet::decrement(RuntimeOrigin::signed(1), 2));
+		assert_eq!(CounterValue::<Test>::get(), Some(4));
+		assert_eq!(interactions(1), Some(2));
+
+		assert_noop!(
+			CustomPallet::increment(RuntimeOrigin::signed(2), 1),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(CustomPallet::remove_from_allow_list(RuntimeOrigin::root(), 1));
+		assert_noop!(
+			CustomPallet::increment(RuntimeOrigin::signed(1), 1),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn allow_list_does_not_restrict_own_counters() {
+	new_test_ext().execute_with(|| {
+		RestrictMutations::set(true);
+
+		assert_ok!(CustomPallet::increment_own(RuntimeOrigin::signed(1), 1));
+	});
+}
+
+#[test]
+fn mutations_are_rate_limited_per_account() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..MaxInteractionsPerPeriod::get() {
+			assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 1));
+		}
+		assert_eq!(RateLimits::<Test>::get(1), Some((1, MaxInteractionsPerPeriod::get())));
+
+		assert_noop!(
+			CustomPallet::increment(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::RateLimited
+		);
+		assert_noop!(
+			CustomPallet::decrement(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::RateLimited
+		);
+
+		// Other accounts are not affected.
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(2), 1));
+	});
+}
+
+#[test]
+fn rate_limit_resets_after_period() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..MaxInteractionsPerPeriod::get() {
+			assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 1));
+		}
+
+		System::set_block_number(RatePeriod::get());
+		assert_noop!(
+			CustomPallet::increment(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::RateLimited
+		);
+
+		System::set_block_number(1 + RatePeriod::get());
+		assert_ok!(CustomPallet::decrement(RuntimeOrigin::signed(1), 1));
+		assert_eq!(RateLimits::<Test>::get(1), Some((1 + RatePeriod::get(), 1)));
+	});
+}
+
+#[test]
+fn failed_mutations_do_not_count_against_rate_limit() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..MaxInteractionsPerPeriod::get() {
+			assert_noop!(
+				CustomPallet::decrement(RuntimeOrigin::signed(1), 1),
+				Error::<Test>::CounterValueBelowZero
+			);
+		}
+
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 1));
+	});
+}
+
+#[test]
+fn rate_limit_does_not_apply_to_root() {
+	new_test_ext().execute_with(|| {
+		for value in 0..=MaxInteractionsPerPeriod::get() {
+			assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), value.into()));
+		}
+	});
+}
+
+#[test]
+fn interactions_record_last_block() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 1));
+		assert_eq!(
+			UserInteractions::<Test>::get(1),
+			Some(InteractionRecord { count: 1, last_block: 1 })
+		);
+
+		System::set_block_number(7);
+		assert_ok!(CustomPallet::decrement(RuntimeOrigin::signed(1), 1));
+		assert_eq!(
+			UserInteractions::<Test>::get(1),
+			Some(InteractionRecord { count: 2, last_block: 7 })
+		);
+	});
+}
+
+#[test]
+fn genesis_config_sets_initial_state() {
+	let genesis = GenesisConfig::<Test> {
+		counter_value: Some(5),
+		interactions: vec![(1, 3)],
+		allow_list: vec![2],
+		authorized_keys: vec![3],
+	};
+	new_test_ext_with_genesis(genesis).execute_with(|| {
+		assert_eq!(CounterValue::<Test>::get(), Some(5));
+		assert_eq!(
+			UserInteractions::<Test>::get(1),
+			Some(InteractionRecord { count: 3, last_block: 0 })
+		);
+		assert!(AllowList::<Test>::contains_key(2));
+		assert!(AuthorizedKeys::<Test>::contains_key(3));
+
+		// Genesis records hold no deposit and keep counting from their initial value.
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 1));
+		assert_eq!(interactions(1), Some(4));
+		assert_eq!(held(HoldReason::InteractionDeposit, 1), 0);
+	});
+}
+
+#[test]
+fn genesis_config_defaults_to_empty_state() {
+	new_test_ext_with_genesis(Default::default()).execute_with(|| {
+		assert_eq!(CounterValue::<Test>::get(), None);
+		assert_eq!(UserInteractions::<Test>::iter().count(), 0);
+		assert_eq!(AllowList::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn genesis_config_accepts_max_value() {
+	let genesis =
+		GenesisConfig::<Test> { counter_value: Some(CounterMaxValue::get()), ..Default::default() };
+	new_test_ext_with_genesis(genesis).execute_with(|| {
+		assert_eq!(CounterValue::<Test>::get(), Some(CounterMaxValue::get()));
+	});
+}
+
+#[test]
+#[should_panic(expected = "the initial counter value exceeds `CounterMaxValue`")]
+fn genesis_config_rejects_values_above_max() {
+	let genesis = GenesisConfig::<Test> {
+		counter_value: Some(CounterMaxValue::get() + 1),
+		..Default::default()
+	};
+	new_test_ext_with_genesis(genesis);
+}
+
+#[test]
+#[should_panic(expected = "duplicate account in the initial interactions")]
+fn genesis_config_rejects_duplicate_interactions() {
+	let genesis =
+		GenesisConfig::<Test> { interactions: vec![(1, 1), (1, 2)], ..Default::default() };
+	new_test_ext_with_genesis(genesis);
+}
+
+#[test]
+fn set_max_value_works() {
+	new_test_ext().execute_with(|| {
+		let max = CounterMaxValue::get() + 5;
+		assert_ok!(CustomPallet::set_max_value(RuntimeOrigin::root(), Some(max)));
+
+		assert_eq!(MaxValueOverride::<Test>::get(), Some(max));
+		assert_eq!(CustomPallet::max_value(), max);
+		System::assert_last_event(Event::MaxValueSet { max_value: Some(max) }.into());
+	});
+}
+
+#[test]
+fn set_max_value_requires_admin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CustomPallet::set_max_value(RuntimeOrigin::signed(1), Some(20)),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn max_value_override_raises_the_cap() {
+	new_test_ext().execute_with(|| {
+		let max = CounterMaxValue::get() + 5;
+		assert_noop!(
+			CustomPallet::increment(RuntimeOrigin::signed(1), max),
+			Error::<Test>::CounterValueExceedsMax
+		);
+
+		assert_ok!(CustomPallet::set_max_value(RuntimeOrigin::root(), Some(max)));
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), max));
+		assert_eq!(CounterValue::<Test>::get(), Some(max));
+		assert_noop!(
+			CustomPallet::set_counter_value(RuntimeOrigin::root(), max + 1),
+			Error::<Test>::CounterValueExceedsMax
+		);
+	});
+}
+
+#[test]
+fn max_value_override_lowers_the_cap() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::set_max_value(RuntimeOrigin::root(), Some(2)));
+
+		assert_noop!(
+			CustomPallet::increment(RuntimeOrigin::signed(1), 3),
+			Error::<Test>::CounterValueExceedsMax
+		);
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 2));
+	});
+}
+
+#[test]
+fn set_max_value_cannot_go_below_counter_value() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), 5));
+
+		assert_noop!(
+			CustomPallet::set_max_value(RuntimeOrigin::root(), Some(4)),
+			Error::<Test>::MaxValueBelowCounterValue
+		);
+		assert_ok!(CustomPallet::set_max_value(RuntimeOrigin::root(), Some(5)));
+	});
+}
+
+#[test]
+fn set_max_value_none_restores_the_default() {
+	new_test_ext().execute_with(|| {
+		let max = CounterMaxValue::get() + 5;
+		assert_ok!(CustomPallet::set_max_value(RuntimeOrigin::root(), Some(max)));
+		assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), max));
+
+		// The default maximum would be below the current value.
+		assert_noop!(
+			CustomPallet::set_max_value(RuntimeOrigin::root(), None),
+			Error::<Test>::MaxValueBelowCounterValue
+		);
+
+		assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), 0));
+		assert_ok!(CustomPallet::set_max_value(RuntimeOrigin::root(), None));
+		assert_eq!(MaxValueOverride::<Test>::get(), None);
+		assert_eq!(CustomPallet::max_value(), CounterMaxValue::get());
+		System::assert_last_event(Event::MaxValueSet { max_value: None }.into());
+	});
+}
+
+#[test]
+fn max_value_override_does_not_apply_to_own_counters() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::set_max_value(
+			RuntimeOrigin::root(),
+			Some(CounterMaxValue::get() + 5)
+		));
+
+		assert_noop!(
+			CustomPallet::increment_own(RuntimeOrigin::signed(1), CounterMaxValue::get() + 1),
+			Error::<Test>::CounterValueExceedsMax
+		);
+	});
+}
+
+#[test]
+fn counter_value_can_exceed_u32() {
+	new_test_ext().execute_with(|| {
+		let beyond_u32 = u64::from(u32::MAX) + 1;
+		assert_ok!(CustomPallet::set_max_value(RuntimeOrigin::root(), Some(beyond_u32 + 1)));
+
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), beyond_u32));
+		assert_eq!(CounterValue::<Test>::get(), Some(beyond_u32));
+		System::assert_last_event(
+			Event::CounterIncremented {
+				counter_value: beyond_u32,
+				who: 1,
+				incremented_amount: beyond_u32,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn integrity_test_passes() {
+	CustomPallet::integrity_test();
+}
+
+#[test]
+#[should_panic(expected = "`CounterMinValue` must not exceed `CounterMaxValue`")]
+fn integrity_test_rejects_min_above_max() {
+	CounterMinValue::set(CounterMaxValue::get() as i64 + 1);
+	CustomPallet::integrity_test();
+}
+
+#[test]
+fn positive_min_is_enforced() {
+	new_test_ext().execute_with(|| {
+		CounterMinValue::set(2);
+		assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), 3));
+
+		assert_noop!(
+			CustomPallet::decrement(RuntimeOrigin::signed(1), 2),
+			Error::<Test>::CounterValueBelowMin
+		);
+		assert_noop!(
+			CustomPallet::set_counter_value(RuntimeOrigin::root(), 1),
+			Error::<Test>::CounterValueBelowMin
+		);
+		assert_ok!(CustomPallet::decrement(RuntimeOrigin::signed(1), 1));
+	});
+}
+
+#[test]
+fn set_max_value_rejects_max_below_positive_min() {
+	new_test_ext().execute_with(|| {
+		CounterMinValue::set(5);
+		assert_eq!(CounterValue::<Test>::get(), None);
+
+		assert_noop!(
+			CustomPallet::set_max_value(RuntimeOrigin::root(), Some(4)),
+			Error::<Test>::MaxValueBelowMinValue
+		);
+		assert_ok!(CustomPallet::set_max_value(RuntimeOrigin::root(), Some(5)));
+	});
+}
+
+#[test]
+fn apply_operations_works() {
+	new_test_ext().execute_with(|| {
+		use CounterOp::*;
+
+		assert_ok!(CustomPallet::apply_operations(
+			RuntimeOrigin::signed(1),
+			ops(&[Increment(3), Decrement(1), Increment(2)])
+		));
+
+		assert_eq!(CounterValue::<Test>::get(), Some(4));
+		assert_eq!(interactions(1), Some(1));
+		assert_eq!(held(HoldReason::InteractionDeposit, 1), InteractionDeposit::get());
+		System::assert_last_event(
+			Event::OperationsApplied { who: Some(1), operations: 3, counter_value: 4 }.into(),
+		);
+		// One event for the batch, besides entering the leaderboard.
+		assert_eq!(System::events().len(), 2);
+	});
+}
+
+#[test]
+fn apply_operations_set_requires_root() {
+	new_test_ext().execute_with(|| {
+		use CounterOp::*;
+
+		assert_noop!(
+			CustomPallet::apply_operations(RuntimeOrigin::signed(1), ops(&[Increment(1), Set(5)])),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			CustomPallet::apply_operations(RuntimeOrigin::root(), ops(&[Set(5), Increment(1)])),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(CustomPallet::apply_operations(RuntimeOrigin::root(), ops(&[Set(5), Set(7)])));
+		assert_eq!(CounterValue::<Test>::get(), Some(7));
+		System::assert_last_event(
+			Event::OperationsApplied { who: None, operations: 2, counter_value: 7 }.into(),
+		);
+	});
+}
+
+#[test]
+fn apply_operations_respects_restricted_origins() {
+	new_test_ext().execute_with(|| {
+		RestrictMutations::set(true);
+
+		assert_noop!(
+			CustomPallet::apply_operations(
+				RuntimeOrigin::signed(1),
+				ops(&[CounterOp::Decrement(0)])
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn apply_operations_is_atomic() {
+	new_test_ext().execute_with(|| {
+		use CounterOp::*;
+
+		// The counter may not exceed the maximum, even if a later operation brings it back.
+		assert_noop!(
+			CustomPallet::apply_operations(
+				RuntimeOrigin::signed(1),
+				ops(&[Increment(5), Increment(6), Decrement(5)])
+			),
+			Error::<Test>::CounterValueExceedsMax
+		);
+		assert_noop!(
+			CustomPallet::apply_operations(
+				RuntimeOrigin::signed(1),
+				ops(&[Increment(1), Decrement(2), Increment(5)])
+			),
+			Error::<Test>::CounterValueBelowZero
+		);
+		assert_noop!(
+			CustomPallet::apply_operations(RuntimeOrigin::root(), ops(&[Set(5), Set(11)])),
+			Error::<Test>::CounterValueExceedsMax
+		);
+
+		assert_eq!(CounterValue::<Test>::get(), None);
+		assert_eq!(interactions(1), None);
+		assert_eq!(RateLimits::<Test>::get(1), None);
+	});
+}
+
+#[test]
+fn apply_operations_follows_the_max_value_override() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::set_max_value(RuntimeOrigin::root(), Some(20)));
+
+		assert_ok!(CustomPallet::apply_operations(
+			RuntimeOrigin::signed(1),
+			ops(&[CounterOp::Increment(10), CounterOp::Increment(10)])
+		));
+		assert_eq!(CounterValue::<Test>::get(), Some(20));
+	});
+}
+
+#[test]
+fn apply_operations_rejects_empty_batches() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CustomPallet::apply_operations(RuntimeOrigin::signed(1), ops(&[])),
+			Error::<Test>::NoOperations
+		);
+	});
+}
+
+#[test]
+fn apply_operations_counts_once_against_the_rate_limit() {
+	new_test_ext().execute_with(|| {
+		let batch = ops(&[CounterOp::Increment(1), CounterOp::Decrement(1)]);
+		for _ in 0..MaxInteractionsPerPeriod::get() {
+			assert_ok!(CustomPallet::apply_operations(RuntimeOrigin::signed(1), batch.clone()));
+		}
+
+		assert_noop!(
+			CustomPallet::apply_operations(RuntimeOrigin::signed(1), batch),
+			Error::<Test>::RateLimited
+		);
+		assert_eq!(interactions(1), Some(MaxInteractionsPerPeriod::get()));
+	});
+}
+
+#[test]
+fn apply_operations_weight_follows_the_batch() {
+	let weight = |n: usize| {
+		crate::Call::<Test>::apply_operations { operations: ops(&vec![CounterOp::Increment(1); n]) }
+			.get_dispatch_info()
+			.call_weight
+	};
+
+	let max_ops = MaxOpsPerCall::get();
+	assert_eq!(weight(1), <() as WeightInfo>::apply_operations(1));
+	assert_eq!(weight(max_ops as usize), <() as WeightInfo>::apply_operations(max_ops));
+	// The benchmarked cost of an operation is too small to add to the weight, but a larger batch
+	// is never lighter.
+	assert!(weight(1).all_lte(weight(max_ops as usize)));
+}
+
+#[test]
+fn reset_counter_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), 5));
+
+		assert_noop!(
+			CustomPallet::reset_counter(RuntimeOrigin::signed(1)),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(CustomPallet::reset_counter(RuntimeOrigin::root()));
+
+		assert_eq!(CounterValue::<Test>::get(), None);
+		System::assert_last_event(Event::CounterReset { era: 0 }.into());
+	});
+}
+
+#[test]
+fn schedule_counter_value_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::schedule_counter_value(RuntimeOrigin::root(), 5, 7));
+		System::assert_last_event(Event::CounterValueScheduled { at: 5, counter_value: 7 }.into());
+
+		run_to_block(4);
+		assert_eq!(CounterValue::<Test>::get(), None);
+
+		run_to_block(5);
+		assert_eq!(CounterValue::<Test>::get(), Some(7));
+		System::assert_has_event(Event::CounterValueSet { counter_value: 7 }.into());
+	});
+}
+
+#[test]
+fn schedule_counter_value_requires_admin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CustomPallet::schedule_counter_value(RuntimeOrigin::signed(1), 5, 7),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			CustomPallet::cancel_scheduled_counter_value(RuntimeOrigin::signed(1), 5),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn scheduled_counter_value_is_checked_when_dispatched() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::schedule_counter_value(RuntimeOrigin::root(), 3, 11));
+		assert_ok!(CustomPallet::schedule_counter_value(RuntimeOrigin::root(), 5, 11));
+		assert_ok!(CustomPallet::set_max_value(RuntimeOrigin::root(), Some(11)));
+		assert_ok!(CustomPallet::set_max_value(RuntimeOrigin::root(), None));
+
+		run_to_block(3);
+		assert_eq!(CounterValue::<Test>::get(), None);
+
+		assert_ok!(CustomPallet::set_max_value(RuntimeOrigin::root(), Some(11)));
+		run_to_block(5);
+		assert_eq!(CounterValue::<Test>::get(), Some(11));
+	});
+}
+
+#[test]
+fn one_counter_value_can_be_scheduled_per_block() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::schedule_counter_value(RuntimeOrigin::root(), 5, 7));
+		assert_noop!(
+			CustomPallet::schedule_counter_value(RuntimeOrigin::root(), 5, 8),
+			Error::<Test>::AlreadyScheduled
+		);
+		assert_ok!(CustomPallet::schedule_counter_value(RuntimeOrigin::root(), 6, 8));
+
+		run_to_block(6);
+		assert_eq!(CounterValue::<Test>::get(), Some(8));
+	});
+}
+
+#[test]
+fn schedule_counter_value_rejects_past_blocks() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CustomPallet::schedule_counter_value(RuntimeOrigin::root(), 1, 7),
+			pallet_scheduler::Error::<Test>::TargetBlockNumberInPast
+		);
+	});
+}
+
+#[test]
+fn cancel_scheduled_counter_value_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::schedule_counter_value(RuntimeOrigin::root(), 5, 7));
+
+		assert_ok!(CustomPallet::cancel_scheduled_counter_value(RuntimeOrigin::root(), 5));
+		System::assert_last_event(Event::ScheduledCounterValueCancelled { at: 5 }.into());
+		assert_noop!(
+			CustomPallet::cancel_scheduled_counter_value(RuntimeOrigin::root(), 5),
+			Error::<Test>::NotScheduled
+		);
+
+		run_to_block(5);
+		assert_eq!(CounterValue::<Test>::get(), None);
+	});
+}
+
+#[test]
+fn schedule_reset_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), 5));
+		assert_ok!(CustomPallet::schedule_reset(RuntimeOrigin::root(), 3, None));
+		System::assert_last_event(Event::ResetScheduled { at: 3, period: None }.into());
+
+		run_to_block(3);
+		assert_eq!(CounterValue::<Test>::get(), None);
+		System::assert_has_event(Event::CounterReset { era: 0 }.into());
+
+		// A one-off reset does not repeat, and another one can be scheduled.
+		assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), 4));
+		run_to_block(10);
+		assert_eq!(CounterValue::<Test>::get(), Some(4));
+		assert_ok!(CustomPallet::schedule_reset(RuntimeOrigin::root(), 11, None));
+	});
+}
+
+#[test]
+fn schedule_reset_repeats_every_period() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::schedule_reset(RuntimeOrigin::root(), 3, Some(5)));
+		assert_noop!(
+			CustomPallet::schedule_reset(RuntimeOrigin::root(), 4, None),
+			Error::<Test>::AlreadyScheduled
+		);
+
+		for reset_at in [3, 8, 13] {
+			assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), 5));
+			run_to_block(reset_at - 1);
+			assert_eq!(CounterValue::<Test>::get(), Some(5));
+			run_to_block(reset_at);
+			assert_eq!(CounterValue::<Test>::get(), None);
+		}
+	});
+}
+
+#[test]
+fn schedule_reset_rejects_zero_period() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CustomPallet::schedule_reset(RuntimeOrigin::root(), 3, Some(0)),
+			Error::<Test>::ZeroPeriod
+		);
+	});
+}
+
+#[test]
+fn cancel_scheduled_reset_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CustomPallet::cancel_scheduled_reset(RuntimeOrigin::root()),
+			Error::<Test>::NotScheduled
+		);
+		assert_ok!(CustomPallet::schedule_reset(RuntimeOrigin::root(), 3, Some(5)));
+		run_to_block(3);
+
+		assert_noop!(
+			CustomPallet::cancel_scheduled_reset(RuntimeOrigin::signed(1)),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(CustomPallet::cancel_scheduled_reset(RuntimeOrigin::root()));
+		System::assert_last_event(Event::ScheduledResetCancelled.into());
+
+		assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), 5));
+		run_to_block(8);
+		assert_eq!(CounterValue::<Test>::get(), Some(5));
+	});
+}
+
+#[test]
+fn counter_is_reset_every_reset_period() {
+	new_test_ext().execute_with(|| {
+		ResetPeriod::set(Some(5));
+		assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), 5));
+
+		run_to_block(4);
+		assert_eq!(CounterValue::<Test>::get(), Some(5));
+
+		run_to_block(5);
+		assert_eq!(CounterValue::<Test>::get(), None);
+		System::assert_last_event(Event::CounterReset { era: 1 }.into());
+
+		assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), 3));
+		run_to_block(10);
+		assert_eq!(CounterValue::<Test>::get(), None);
+		System::assert_last_event(Event::CounterReset { era: 2 }.into());
+	});
+}
+
+#[test]
+fn counter_is_not_reset_without_reset_period() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), 5));
+
+		run_to_block(20);
+		assert_eq!(CounterValue::<Test>::get(), Some(5));
+	});
+}
+
+#[test]
+fn on_initialize_only_charges_reset_blocks() {
+	new_test_ext().execute_with(|| {
+		ResetPeriod::set(Some(5));
+
+		assert_eq!(CustomPallet::on_initialize(4), Weight::zero());
+		assert_eq!(CustomPallet::on_initialize(5), <() as WeightInfo>::on_initialize_reset());
+	});
+}
+
+#[test]
+fn reset_counter_reports_the_current_era() {
+	new_test_ext().execute_with(|| {
+		ResetPeriod::set(Some(5));
+		run_to_block(7);
+
+		assert_ok!(CustomPallet::reset_counter(RuntimeOrigin::root()));
+		System::assert_last_event(Event::CounterReset { era: 1 }.into());
+	});
+}
+
+#[test]
+fn interactions_outlive_eras_by_default() {
+	new_test_ext().execute_with(|| {
+		ResetPeriod::set(Some(5));
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 1));
+
+		run_to_block(5);
+		assert_eq!(CustomPallet::interactions_of(&1), 1);
+
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 1));
+		assert_eq!(interactions(1), Some(2));
+	});
+}
+
+#[test]
+fn interactions_restart_every_era_with_reset_interactions() {
+	new_test_ext().execute_with(|| {
+		ResetPeriod::set(Some(5));
+		ResetInteractions::set(true);
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 1));
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 1));
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(2), 1));
+		assert_eq!(CustomPallet::interactions_of(&1), 2);
+
+		run_to_block(5);
+
+		// The records of the past era are kept, but no longer count.
+		assert_eq!(interactions(1), Some(2));
+		assert_eq!(CustomPallet::interactions_of(&1), 0);
+		assert_eq!(CustomPallet::top_interactors(10), vec![]);
+
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 1));
+		assert_eq!(interactions(1), Some(1));
+		assert_eq!(CustomPallet::top_interactors(10), vec![(1, 1)]);
+		assert_eq!(held(HoldReason::InteractionDeposit, 1), InteractionDeposit::get());
+	});
+}
+
+#[test]
+#[should_panic(expected = "`ResetPeriod` must not be zero")]
+fn integrity_test_rejects_zero_reset_period() {
+	ResetPeriod::set(Some(0));
+	CustomPallet::integrity_test();
+}
+
+#[test]
+fn counter_history_records_every_change() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), 5));
+		System::set_block_number(2);
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 1));
+		System::set_block_number(3);
+		assert_ok!(CustomPallet::decrement(RuntimeOrigin::signed(1), 2));
+		System::set_block_number(4);
+		assert_ok!(CustomPallet::apply_operations(
+			RuntimeOrigin::signed(1),
+			ops(&[CounterOp::Increment(3), CounterOp::Increment(1)])
+		));
+
+		assert_eq!(history(), vec![(1, 5), (2, 6), (3, 4), (4, 8)]);
+	});
+}
+
+#[test]
+fn counter_history_keeps_the_last_value_of_a_block() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 3));
+		assert_ok!(CustomPallet::decrement(RuntimeOrigin::signed(1), 1));
+		assert_noop!(
+			CustomPallet::decrement(RuntimeOrigin::signed(1), 3),
+			Error::<Test>::CounterValueBelowZero
+		);
+
+		assert_eq!(history(), vec![(1, 2)]);
+	});
+}
+
+#[test]
+fn counter_history_drops_the_oldest_entries() {
+	new_test_ext().execute_with(|| {
+		for block in 1..=6 {
+			System::set_block_number(block);
+			assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), block));
+		}
+
+		assert_eq!(history().len(), MaxHistory::get() as usize);
+		assert_eq!(history(), vec![(3, 3), (4, 4), (5, 5), (6, 6)]);
+	});
+}
+
+#[test]
+fn counter_history_records_resets() {
+	new_test_ext().execute_with(|| {
+		ResetPeriod::set(Some(5));
+		assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), 5));
+		run_to_block(5);
+		System::set_block_number(7);
+		assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), 2));
+		assert_ok!(CustomPallet::reset_counter(RuntimeOrigin::root()));
+
+		assert_eq!(history(), vec![(1, 5), (5, 0), (7, 0)]);
+	});
+}
+
+#[test]
+fn genesis_config_records_the_initial_value() {
+	let genesis = GenesisConfig::<Test> { counter_value: Some(3), ..Default::default() };
+	new_test_ext_with_genesis(genesis).execute_with(|| {
+		assert_eq!(history(), vec![(0, 3)]);
+	});
+}
+
+#[test]
+fn counter_value_at_works() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(CustomPallet::counter_value_at(1), None);
+
+		System::set_block_number(3);
+		assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), 5));
+		System::set_block_number(6);
+		assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), 7));
+
+		assert_eq!(CustomPallet::counter_value_at(2), None);
+		assert_eq!(CustomPallet::counter_value_at(3), Some(5));
+		assert_eq!(CustomPallet::counter_value_at(5), Some(5));
+		assert_eq!(CustomPallet::counter_value_at(6), Some(7));
+		assert_eq!(CustomPallet::counter_value_at(100), Some(7));
+	});
+}
+
+#[test]
+fn leaderboard_ranks_the_most_active_accounts() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 1));
+		System::assert_has_event(Event::LeaderboardChanged { who: 1, rank: Some(0) }.into());
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(2), 1));
+		System::assert_has_event(Event::LeaderboardChanged { who: 2, rank: Some(1) }.into());
+		assert_ok!(CustomPallet::decrement(RuntimeOrigin::signed(2), 1));
+		System::assert_has_event(Event::LeaderboardChanged { who: 2, rank: Some(0) }.into());
+		assert_eq!(leaderboard(), vec![(2, 2), (1, 1)]);
+
+		// Ties are ranked by who got there first, so the rank of account 1 does not change.
+		System::reset_events();
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 1));
+		assert_eq!(leaderboard(), vec![(2, 2), (1, 2)]);
+		assert!(leaderboard_events().is_empty());
+
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 1));
+		assert_eq!(leaderboard(), vec![(1, 3), (2, 2)]);
+		assert_eq!(leaderboard_events(), vec![(1, Some(0))]);
+		assert_eq!(CustomPallet::top_interactors(1), vec![(1, 3)]);
+	});
+}
+
+#[test]
+fn leaderboard_keeps_the_top_max_leaderboard_accounts() {
+	new_test_ext().execute_with(|| {
+		for who in 1..=3 {
+			assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(who), 1));
+			assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(who), 1));
+		}
+		assert_eq!(leaderboard(), vec![(1, 2), (2, 2), (3, 2)]);
+
+		// Tying the last entry of a full leaderboard is not enough to enter it.
+		System::reset_events();
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(4), 1));
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(4), 1));
+		assert_eq!(leaderboard(), vec![(1, 2), (2, 2), (3, 2)]);
+		assert!(leaderboard_events().is_empty());
+
+		// Beating it pushes the last entry out.
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(4), 1));
+		assert_eq!(leaderboard(), vec![(4, 3), (1, 2), (2, 2)]);
+		System::assert_has_event(Event::LeaderboardChanged { who: 4, rank: Some(0) }.into());
+	});
+}
+
+#[test]
+fn clear_interactions_leaves_the_leaderboard() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 1));
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(2), 1));
+		run_to_block(1 + RatePeriod::get());
+
+		assert_ok!(CustomPallet::clear_interactions(RuntimeOrigin::signed(1)));
+
+		assert_eq!(leaderboard(), vec![(2, 1)]);
+		System::assert_has_event(Event::LeaderboardChanged { who: 1, rank: None }.into());
+	});
+}
+
+#[test]
+fn genesis_config_builds_the_leaderboard() {
+	let genesis = GenesisConfig::<Test> {
+		interactions: vec![(1, 3), (2, 5), (3, 1), (4, 4)],
+		..Default::default()
+	};
+	new_test_ext_with_genesis(genesis).execute_with(|| {
+		assert_eq!(leaderboard(), vec![(2, 5), (4, 4), (1, 3)]);
+	});
+}
+
+#[test]
+fn leaderboard_is_cleared_every_era_with_reset_interactions() {
+	new_test_ext().execute_with(|| {
+		ResetPeriod::set(Some(5));
+		ResetInteractions::set(true);
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 1));
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(2), 1));
+
+		run_to_block(5);
+		assert_eq!(leaderboard(), vec![]);
+
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(2), 1));
+		assert_eq!(leaderboard(), vec![(2, 1)]);
+	});
+}
+
+#[test]
+fn leaderboard_outlives_eras_by_default() {
+	new_test_ext().execute_with(|| {
+		ResetPeriod::set(Some(5));
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 1));
+
+		run_to_block(5);
+		assert_eq!(leaderboard(), vec![(1, 1)]);
+	});
+}
+
+#[test]
+fn add_authorized_key_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::add_authorized_key(RuntimeOrigin::root(), 1));
+		assert!(AuthorizedKeys::<Test>::contains_key(1));
+		System::assert_last_event(Event::AuthorizedKeyAdded { who: 1 }.into());
+
+		assert_noop!(
+			CustomPallet::add_authorized_key(RuntimeOrigin::root(), 1),
+			Error::<Test>::AlreadyAuthorized
+		);
+	});
+}
+
+#[test]
+fn remove_authorized_key_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CustomPallet::remove_authorized_key(RuntimeOrigin::root(), 1),
+			Error::<Test>::NotAuthorized
+		);
+		assert_ok!(CustomPallet::add_authorized_key(RuntimeOrigin::root(), 1));
+
+		assert_ok!(CustomPallet::remove_authorized_key(RuntimeOrigin::root(), 1));
+		assert!(!AuthorizedKeys::<Test>::contains_key(1));
+		System::assert_last_event(Event::AuthorizedKeyRemoved { who: 1 }.into());
+	});
+}
+
+#[test]
+fn authorized_keys_are_managed_by_admin_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CustomPallet::add_authorized_key(RuntimeOrigin::signed(1), 1),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			CustomPallet::remove_authorized_key(RuntimeOrigin::signed(1), 1),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn submit_external_value_works() {
+	let genesis = GenesisConfig::<Test> { authorized_keys: vec![1], ..Default::default() };
+	new_test_ext_with_genesis(genesis).execute_with(|| {
+		assert_ok!(CustomPallet::submit_external_value(RuntimeOrigin::signed(1), 7));
+
+		assert_eq!(CounterValue::<Test>::get(), Some(7));
+		assert_eq!(history(), vec![(1, 7)]);
+		System::assert_last_event(
+			Event::ExternalValueSubmitted { who: 1, counter_value: 7 }.into(),
+		);
+
+		// External values are not interactions.
+		assert_eq!(interactions(1), None);
+		assert_eq!(RateLimits::<Test>::get(1), None);
+	});
+}
+
+#[test]
+fn submit_external_value_requires_authorized_key() {
+	let genesis = GenesisConfig::<Test> { authorized_keys: vec![1], ..Default::default() };
+	new_test_ext_with_genesis(genesis).execute_with(|| {
+		assert_noop!(
+			CustomPallet::submit_external_value(RuntimeOrigin::signed(2), 7),
+			Error::<Test>::NotAuthorized
+		);
+		assert_noop!(
+			CustomPallet::submit_external_value(RuntimeOrigin::root(), 7),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn submit_external_value_respects_bounds() {
+	let genesis = GenesisConfig::<Test> { authorized_keys: vec![1], ..Default::default() };
+	new_test_ext_with_genesis(genesis).execute_with(|| {
+		assert_noop!(
+			CustomPallet::submit_external_value(RuntimeOrigin::signed(1), 11),
+			Error::<Test>::CounterValueExceedsMax
+		);
+	});
+}
+
+#[test]
+fn offchain_worker_submits_the_external_value() {
+	let (mut ext, offchain_state, pool_state) = new_offchain_test_ext();
+	ext.execute_with(|| {
+		set_authorized_key(1);
+		set_external_url();
+		expect_external_value(&mut offchain_state.write(), b"7\n");
+
+		CustomPallet::offchain_worker(5);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		assert!(pool_state.read().transactions.is_empty());
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert!(matches!(tx.preamble, Preamble::Signed(1, _, _)));
+		assert_eq!(
+			tx.function,
+			RuntimeCall::CustomPallet(crate::Call::submit_external_value { counter_value: 7 })
+		);
+	});
+}
+
+#[test]
+fn offchain_worker_runs_every_offchain_interval() {
+	let (mut ext, offchain_state, pool_state) = new_offchain_test_ext();
+	ext.execute_with(|| {
+		set_authorized_key(1);
+		set_external_url();
+
+		// No request is expected in between.
+		CustomPallet::offchain_worker(3);
+		assert!(pool_state.read().transactions.is_empty());
+
+		expect_external_value(&mut offchain_state.write(), b"7");
+		CustomPallet::offchain_worker(10);
+		assert_eq!(pool_state.read().transactions.len(), 1);
+	});
+}
+
+#[test]
+fn offchain_worker_skips_unchanged_values() {
+	let (mut ext, offchain_state, pool_state) = new_offchain_test_ext();
+	ext.execute_with(|| {
+		set_authorized_key(1);
+		set_external_url();
+		assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), 7));
+		expect_external_value(&mut offchain_state.write(), b"7");
+
+		CustomPallet::offchain_worker(5);
+
+		assert!(pool_state.read().transactions.is_empty());
+	});
+}
+
+#[test]
+fn offchain_worker_skips_invalid_values() {
+	let (mut ext, offchain_state, pool_state) = new_offchain_test_ext();
+	ext.execute_with(|| {
+		set_authorized_key(1);
+		set_external_url();
+
+		for body in [&b"seven"[..], b"11", b"-1"] {
+			expect_external_value(&mut offchain_state.write(), body);
+			CustomPallet::offchain_worker(5);
+		}
+
+		assert!(pool_state.read().transactions.is_empty());
+	});
+}
+
+#[test]
+fn offchain_worker_requires_a_url_and_a_key() {
+	let (mut ext, _, pool_state) = new_offchain_test_ext();
+	ext.execute_with(|| {
+		// Without a URL, no request is sent.
+		set_authorized_key(1);
+		CustomPallet::offchain_worker(5);
+
+		// Without a key, neither.
+		UintAuthorityId::set_all_keys(Vec::<u64>::new());
+		set_external_url();
+		CustomPallet::offchain_worker(5);
+
+		assert!(pool_state.read().transactions.is_empty());
+	});
+}
+
+#[test]
+fn offchain_worker_requires_an_authorized_key() {
+	let (mut ext, _, pool_state) = new_offchain_test_ext();
+	ext.execute_with(|| {
+		// The local key is not authorized: no request is sent.
+		UintAuthorityId::set_all_keys([1]);
+		set_external_url();
+		CustomPallet::offchain_worker(5);
+
+		assert!(pool_state.read().transactions.is_empty());
+	});
+}
+
+#[test]
+fn offchain_worker_signs_with_an_authorized_key() {
+	let (mut ext, offchain_state, pool_state) = new_offchain_test_ext();
+	ext.execute_with(|| {
+		UintAuthorityId::set_all_keys([1, 2]);
+		AuthorizedKeys::<Test>::insert(2, ());
+		set_external_url();
+		expect_external_value(&mut offchain_state.write(), b"7");
+
+		CustomPallet::offchain_worker(5);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert!(matches!(tx.preamble, Preamble::Signed(2, _, _)));
+	});
+}
+
+#[test]
+fn external_values_are_parsed_into_the_counter_type() {
+	assert_eq!(crate::parse_external_value::<u64>(b" 18446744073709551615\n"), Some(u64::MAX));
+	assert_eq!(
+		crate::parse_external_value::<u128>(b"340282366920938463463374607431768211455"),
+		Some(u128::MAX)
+	);
+	assert_eq!(crate::parse_external_value::<i64>(b"-3"), Some(-3));
+	assert_eq!(crate::parse_external_value::<u64>(b"-3"), None);
+	assert_eq!(crate::parse_external_value::<u64>(b"18446744073709551616"), None);
+	assert_eq!(crate::parse_external_value::<u64>(b"seven"), None);
+	assert_eq!(crate::parse_external_value::<u64>(&[0xff, b'7']), None);
+}
+
+#[test]
+fn increment_unsigned_works() {
+	new_test_ext().execute_with(|| {
+		// Account 9 has no funds.
+		assert_eq!(Balances::balance(&9), 0);
+
+		assert_ok!(dispatch_unsigned(increment_unsigned(9, 2, 0)));
+
+		assert_eq!(CounterValue::<Test>::get(), Some(2));
+		assert_eq!(interactions(9), Some(1));
+		assert_eq!(UnsignedNonces::<Test>::get(9), 1);
+		assert_eq!(held(HoldReason::InteractionDeposit, 9), 0);
+		System::assert_last_event(
+			Event::CounterIncremented { counter_value: 2, who: 9, incremented_amount: 2 }.into(),
+		);
+
+		// Only the first increment is feeless.
+		let call = increment_unsigned(9, 1, 1);
+		assert_eq!(validate(&call), Err(ValidityError::AlreadyInteracted.into()));
+		assert_eq!(CustomPallet::pre_dispatch(&call), Err(ValidityError::AlreadyInteracted.into()));
+		assert_ok!(CustomPallet::increment_unsigned(
+			RuntimeOrigin::none(),
+			increment_payload(9, 1, 1),
+			TestSignature(9, vec![]),
+		));
+		assert_eq!(CounterValue::<Test>::get(), Some(2));
+		assert_eq!(interactions(9), Some(1));
+		System::assert_last_event(
+			Event::UnsignedIncrementFailed {
+				who: 9,
+				error: Error::<Test>::AlreadyInteracted.into(),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn unsigned_increments_cannot_be_zero() {
+	new_test_ext().execute_with(|| {
+		for who in 10..60 {
+			let call = increment_unsigned(who, 0, 0);
+			assert_eq!(validate(&call), Err(ValidityError::ZeroAmount.into()));
+			assert_eq!(CustomPallet::pre_dispatch(&call), Err(ValidityError::ZeroAmount.into()));
+			assert_noop!(
+				CustomPallet::increment_unsigned(
+					RuntimeOrigin::none(),
+					increment_payload(who, 0, 0),
+					TestSignature(who, vec![]),
+				),
+				Error::<Test>::ZeroAmount
+			);
+		}
+
+		assert_eq!(UserInteractions::<Test>::iter().count(), 0);
+		assert_eq!(UnsignedNonces::<Test>::iter().count(), 0);
+		assert_eq!(RateLimits::<Test>::iter().count(), 0);
+		assert!(TopInteractors::<Test>::get().is_empty());
+	});
+}
+
+#[test]
+fn unsigned_increments_are_capped_per_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for who in 10..13 {
+			assert_ok!(dispatch_unsigned(increment_unsigned(who, 1, 0)));
+		}
+
+		// Valid in the pool, but left for a later block.
+		let call = increment_unsigned(13, 1, 0);
+		assert_ok!(validate(&call));
+		assert_eq!(
+			CustomPallet::pre_dispatch(&call),
+			Err(InvalidTransaction::ExhaustsResources.into())
+		);
+		assert_noop!(
+			CustomPallet::increment_unsigned(
+				RuntimeOrigin::none(),
+				increment_payload(13, 1, 0),
+				TestSignature(13, vec![]),
+			),
+			Error::<Test>::TooManyUnsignedIncrements
+		);
+
+		System::set_block_number(2);
+		assert_ok!(dispatch_unsigned(call));
+		assert_eq!(CounterValue::<Test>::get(), Some(4));
+	});
+}
+
+#[test]
+fn increment_unsigned_requires_none_origin() {
+	new_test_ext().execute_with(|| {
+		let payload = increment_payload(1, 1, 0);
+		let signature = TestSignature(1, payload.encode());
+		assert_noop!(
+			CustomPallet::increment_unsigned(RuntimeOrigin::signed(1), payload, signature),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn validate_unsigned_works() {
+	new_test_ext().execute_with(|| {
+		let tx = ValidTransaction::with_tag_prefix("CustomPalletIncrementUnsigned")
+			.priority(UnsignedPriority::get())
+			.longevity(UnsignedLongevity::get())
+			.propagate(true);
+
+		assert_eq!(
+			validate(&increment_unsigned(9, 1, 0)),
+			tx.clone().and_provides((9u64, 0u32)).build()
+		);
+
+		// Later payloads wait for the previous one.
+		assert_eq!(
+			validate(&increment_unsigned(9, 1, 2)),
+			tx.and_provides((9u64, 2u32)).and_requires((9u64, 1u32)).build()
+		);
+	});
+}
+
+#[test]
+fn validate_unsigned_checks_the_signature() {
+	new_test_ext().execute_with(|| {
+		let payload = increment_payload(9, 1, 0);
+
+		// Signed by another account.
+		let signature = TestSignature(8, payload.encode());
+		let call = crate::Call::increment_unsigned { payload: payload.clone(), signature };
+		assert_eq!(validate(&call), InvalidTransaction::BadProof.into());
+
+		// Signed for another amount.
+		let signature =
+			TestSignature(9, IncrementPayload { amount: 5, ..payload.clone() }.encode());
+		let call = crate::Call::increment_unsigned { payload, signature };
+		assert_eq!(validate(&call), InvalidTransaction::BadProof.into());
+	});
+}
+
+#[test]
+fn unsigned_payloads_are_bound_to_the_chain() {
+	new_test_ext().execute_with(|| {
+		let payload = IncrementPayload { genesis_hash: H256::zero(), ..increment_payload(9, 1, 0) };
+		let signature = TestSignature(9, payload.encode());
+		let call = crate::Call::increment_unsigned { payload, signature };
+
+		assert_eq!(validate(&call), InvalidTransaction::BadProof.into());
+		assert_eq!(CustomPallet::pre_dispatch(&call), Err(InvalidTransaction::BadProof.into()));
+	});
+}
+
+#[test]
+fn unsigned_payloads_cannot_be_replayed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(dispatch_unsigned(increment_unsigned(9, 1, 0)));
+
+		assert_eq!(validate(&increment_unsigned(9, 1, 0)), InvalidTransaction::Stale.into());
+		assert_noop!(
+			CustomPallet::increment_unsigned(
+				RuntimeOrigin::none(),
+				increment_payload(9, 1, 0),
+				TestSignature(9, vec![]),
+			),
+			Error::<Test>::InvalidNonce
+		);
+
+		// Payloads ahead of the next nonce are only included after it.
+		assert_eq!(
+			CustomPallet::pre_dispatch(&increment_unsigned(9, 1, 2)),
+			Err(InvalidTransaction::Future.into())
+		);
+	});
+}
+
+#[test]
+fn increment_unsigned_follows_the_increment_rules() {
+	new_test_ext().execute_with(|| {
+		// The signer must be admitted by the `IncrementOrigin`.
+		RestrictMutations::set(true);
+		let call = increment_unsigned(9, 1, 0);
+		assert_eq!(validate(&call), InvalidTransaction::BadSigner.into());
+		assert_eq!(CustomPallet::pre_dispatch(&call), Err(InvalidTransaction::BadSigner.into()));
+		RestrictMutations::set(false);
+
+		for _ in 0..3 {
+			assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 1));
+		}
+		let call = increment_unsigned(1, 1, 0);
+		assert_eq!(validate(&call), Err(ValidityError::RateLimited.into()));
+		assert_eq!(CustomPallet::pre_dispatch(&call), Err(ValidityError::RateLimited.into()));
+
+		let call = increment_unsigned(8, 8, 0);
+		assert_eq!(validate(&call), Err(ValidityError::CounterValueExceedsMax.into()));
+		assert_eq!(
+			CustomPallet::pre_dispatch(&call),
+			Err(ValidityError::CounterValueExceedsMax.into())
+		);
+	});
+}
+
+#[test]
+fn failed_unsigned_increments_consume_the_nonce() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let call = increment_unsigned(9, 2, 0);
+		assert_ok!(validate(&call));
+
+		// The counter changed between validation and inclusion.
+		CounterValue::<Test>::put(9);
+		let crate::Call::increment_unsigned { payload, signature } = call.clone() else {
+			unreachable!()
+		};
+		assert_ok!(CustomPallet::increment_unsigned(RuntimeOrigin::none(), payload, signature));
+
+		assert_eq!(CounterValue::<Test>::get(), Some(9));
+		assert_eq!(interactions(9), None);
+		assert_eq!(UnsignedNonces::<Test>::get(9), 1);
+		System::assert_last_event(
+			Event::UnsignedIncrementFailed {
+				who: 9,
+				error: Error::<Test>::CounterValueExceedsMax.into(),
+			}
+			.into(),
+		);
+		assert_eq!(validate(&call), InvalidTransaction::Stale.into());
+	});
+}
+
+#[test]
+fn check_counter_bounds_accepts_calls_within_bounds() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(check_bounds(crate::Call::increment { amount_to_increment: 10 }));
+		assert_ok!(check_bounds(crate::Call::decrement { amount_to_decrement: 0 }));
+
+		CounterValue::<Test>::put(4);
+		assert_ok!(check_bounds(crate::Call::increment { amount_to_increment: 6 }));
+		assert_ok!(check_bounds(crate::Call::decrement { amount_to_decrement: 4 }));
+	});
+}
+
+#[test]
+fn check_counter_bounds_rejects_increments_above_max() {
+	new_test_ext().execute_with(|| {
+		CounterValue::<Test>::put(4);
+		assert_eq!(
+			check_bounds(crate::Call::increment { amount_to_increment: 7 }),
+			Err(ValidityError::CounterValueExceedsMax.into())
+		);
+		assert_eq!(
+			check_bounds(crate::Call::increment { amount_to_increment: u64::MAX }),
+			Err(ValidityError::CounterValueExceedsMax.into())
+		);
+
+		// The maximum set by the admin is enforced as well.
+		MaxValueOverride::<Test>::put(5);
+		assert_ok!(check_bounds(crate::Call::increment { amount_to_increment: 1 }));
+		assert_eq!(
+			check_bounds(crate::Call::increment { amount_to_increment: 2 }),
+			Err(InvalidTransaction::Custom(ValidityError::CounterValueExceedsMax as u8).into())
+		);
+	});
+}
+
+#[test]
+fn check_counter_bounds_rejects_decrements_below_min() {
+	new_test_ext().execute_with(|| {
+		CounterValue::<Test>::put(4);
+		assert_eq!(
+			check_bounds(crate::Call::decrement { amount_to_decrement: 5 }),
+			Err(ValidityError::CounterValueBelowMin.into())
+		);
+	});
+}
+
+#[test]
+fn check_counter_bounds_ignores_other_calls() {
+	new_test_ext().execute_with(|| {
+		CounterValue::<Test>::put(CounterMaxValue::get());
+		assert_ok!(check_bounds(crate::Call::increment_own { amount_to_increment: 1 }));
+		assert_ok!(check_bounds(crate::Call::set_counter_value { new_value: 0 }));
+	});
+}
+
+#[test]
+fn increment_from_remote_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::increment_from_remote(para(2000), 2000.into(), 3));
+		assert_eq!(CounterValue::<Test>::get(), Some(3));
+		assert_eq!(para_interactions(2000), Some(1));
+		System::assert_last_event(
+			Event::CounterIncrementedFromRemote {
+				counter_value: 3,
+				para_id: 2000.into(),
+				incremented_amount: 3,
+			}
+			.into(),
+		);
+
+		assert_ok!(CustomPallet::increment_from_remote(para(2000), 2000.into(), 2));
+		assert_eq!(CounterValue::<Test>::get(), Some(5));
+		assert_eq!(
+			ParaInteractions::<Test>::get(ParaId::from(2000)),
+			Some(InteractionRecord { count: 2, last_block: 1 })
+		);
+
+		// Parachains are not accounts: no account record, leaderboard entry or deposit.
+		assert_eq!(interactions(PARA_ACCOUNT_OFFSET + 2000), None);
+		assert_eq!(leaderboard(), vec![]);
+	});
+}
+
+#[test]
+fn increment_from_remote_requires_xcm_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CustomPallet::increment_from_remote(RuntimeOrigin::signed(1), 2000.into(), 1),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			CustomPallet::increment_from_remote(RuntimeOrigin::root(), 2000.into(), 1),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			CustomPallet::increment_from_remote(RuntimeOrigin::none(), 2000.into(), 1),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn increment_from_remote_requires_the_sending_para_id() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CustomPallet::increment_from_remote(para(2000), 2001.into(), 1),
+			Error::<Test>::ParaIdMismatch
+		);
+	});
+}
+
+#[test]
+fn increment_from_remote_respects_the_bounds() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CustomPallet::increment_from_remote(
+				para(2000),
+				2000.into(),
+				CounterMaxValue::get() + 1
+			),
+			Error::<Test>::CounterValueExceedsMax
+		);
+
+		MaxValueOverride::<Test>::put(4);
+		assert_noop!(
+			CustomPallet::increment_from_remote(para(2000), 2000.into(), 5),
+			Error::<Test>::CounterValueExceedsMax
+		);
+	});
+}
+
+#[test]
+fn increment_from_remote_is_not_rate_limited() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..MaxInteractionsPerPeriod::get() + 1 {
+			assert_ok!(CustomPallet::increment_from_remote(para(2000), 2000.into(), 1));
+		}
+		assert_eq!(para_interactions(2000), Some(MaxInteractionsPerPeriod::get() + 1));
+	});
+}
+
+#[test]
+fn para_interactions_are_tracked_per_parachain() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::increment_from_remote(para(2000), 2000.into(), 1));
+		assert_ok!(CustomPallet::increment_from_remote(para(2001), 2001.into(), 1));
+		assert_ok!(CustomPallet::increment_from_remote(para(2000), 2000.into(), 1));
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 1));
+
+		assert_eq!(CounterValue::<Test>::get(), Some(4));
+		assert_eq!(para_interactions(2000), Some(2));
+		assert_eq!(para_interactions(2001), Some(1));
+		assert_eq!(para_interactions(1), None);
+		assert_eq!(interactions(1), Some(1));
+	});
+}
+
+#[test]
+fn para_interactions_restart_every_era_with_reset_interactions() {
+	new_test_ext().execute_with(|| {
+		ResetPeriod::set(Some(5));
+		ResetInteractions::set(true);
+		assert_ok!(CustomPallet::increment_from_remote(para(2000), 2000.into(), 1));
+		assert_ok!(CustomPallet::increment_from_remote(para(2000), 2000.into(), 1));
+		assert_eq!(para_interactions(2000), Some(2));
+
+		run_to_block(5);
+
+		assert_ok!(CustomPallet::increment_from_remote(para(2000), 2000.into(), 1));
+		assert_eq!(para_interactions(2000), Some(1));
+	});
+}
+
+#[test]
+fn counter_inspect_reads_the_pallet() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 3));
+		assert_ok!(CustomPallet::set_max_value(RuntimeOrigin::root(), Some(8)));
+
+		assert_eq!(<CustomPallet as CounterInspect<u64, u64>>::counter_value(), 3);
+		assert_eq!(<CustomPallet as CounterInspect<u64, u64>>::max_value(), 8);
+		assert_eq!(<CustomPallet as CounterInspect<u64, u64>>::interactions_of(&1), 1);
+		assert_eq!(<CustomPallet as CounterInspect<u64, u64>>::interactions_of(&2), 0);
+	});
+}
+
+#[test]
+fn counter_mutate_changes_the_counter_on_behalf_of_the_account() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(<CustomPallet as CounterMutate<u64, u64>>::increment(&1, 5));
+		System::assert_last_event(
+			Event::CounterIncremented { counter_value: 5, who: 1, incremented_amount: 5 }.into(),
+		);
+		assert_ok!(<CustomPallet as CounterMutate<u64, u64>>::decrement(&1, 2));
+		System::assert_last_event(
+			Event::CounterDecremented { counter_value: 3, who: 1, decremented_amount: 2 }.into(),
+		);
+
+		assert_eq!(CounterValue::<Test>::get(), Some(3));
+		assert_eq!(interactions(1), Some(2));
+		assert_eq!(held(HoldReason::InteractionDeposit, 1), InteractionDeposit::get());
+	});
+}
+
+#[test]
+fn counter_mutate_is_checked_like_the_calls() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			<CustomPallet as CounterMutate<u64, u64>>::increment(&1, CounterMaxValue::get() + 1),
+			Error::<Test>::CounterValueExceedsMax
+		);
+		assert_noop!(
+			<CustomPallet as CounterMutate<u64, u64>>::decrement(&1, 1),
+			Error::<Test>::CounterValueBelowZero
+		);
+
+		for _ in 0..MaxInteractionsPerPeriod::get() {
+			assert_ok!(<CustomPallet as CounterMutate<u64, u64>>::increment(&1, 1));
+		}
+		assert_noop!(
+			<CustomPallet as CounterMutate<u64, u64>>::increment(&1, 1),
+			Error::<Test>::RateLimited
+		);
+	});
+}
+
+#[test]
+fn noop_counter_ignores_changes() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(<() as CounterMutate<u64, u64>>::increment(&1, 5));
+		assert_ok!(<() as CounterMutate<u64, u64>>::decrement(&1, 7));
+
+		assert_eq!(<() as CounterInspect<u64, u64>>::counter_value(), 0);
+		assert_eq!(<() as CounterInspect<u64, u64>>::max_value(), 0);
+		assert_eq!(<() as CounterInspect<u64, u64>>::interactions_of(&1), 0);
+		assert_eq!(CounterValue::<Test>::get(), None);
+		assert_eq!(interactions(1), None);
+	});
+}
@@ -0,0 +1,229 @@
+//! Tests of the signed counter mode, run against the `i64` runtime of [`crate::mock::signed`].
+
+use crate::{
+	extensions::ValidityError,
+	mock::{signed::*, MaxNameLength, PARA_ACCOUNT_OFFSET},
+	CheckCounterBounds, CounterOp, CounterValue, Counters, CountersByAccount, Error, Event,
+	GenesisConfig, IncrementPayload,
+};
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok, dispatch::GetDispatchInfo, BoundedVec};
+use sp_runtime::{
+	testing::{TestSignature, UintAuthorityId},
+	traits::{DispatchTransaction, ValidateUnsigned},
+	transaction_validity::{TransactionSource, TransactionValidityError},
+};
+
+fn name(name: &[u8]) -> BoundedVec<u8, MaxNameLength> {
+	name.to_vec().try_into().unwrap()
+}
+
+/// Validate the signed transaction `call` against [`CheckCounterBounds`].
+fn check_bounds(call: crate::Call<Test>) -> Result<(), TransactionValidityError> {
+	let call = RuntimeCall::CustomPallet(call);
+	CheckCounterBounds::<Test>::new()
+		.validate_only(
+			RuntimeOrigin::signed(1),
+			&call,
+			&call.get_dispatch_info(),
+			0,
+			TransactionSource::External,
+			0,
+		)
+		.map(|_| ())
+}
+
+#[test]
+fn signed_mode_allows_negative_values() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::decrement(RuntimeOrigin::signed(1), 4));
+		assert_eq!(CounterValue::<Test>::get(), Some(-4));
+		System::assert_last_event(
+			Event::CounterDecremented { counter_value: -4, who: 1, decremented_amount: 4 }.into(),
+		);
+
+		assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(1), 6));
+		assert_eq!(CounterValue::<Test>::get(), Some(2));
+
+		assert_ok!(CustomPallet::set_counter_value(RuntimeOrigin::root(), -10));
+		assert_eq!(CounterValue::<Test>::get(), Some(-10));
+	});
+}
+
+#[test]
+fn signed_mode_rejects_values_below_min() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CustomPallet::decrement(RuntimeOrigin::signed(1), 11),
+			Error::<Test>::CounterValueBelowMin
+		);
+		assert_noop!(
+			CustomPallet::set_counter_value(RuntimeOrigin::root(), -11),
+			Error::<Test>::CounterValueBelowMin
+		);
+
+		assert_ok!(CustomPallet::decrement(RuntimeOrigin::signed(1), 10));
+		assert_noop!(
+			CustomPallet::decrement(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::CounterValueBelowMin
+		);
+	});
+}
+
+#[test]
+fn signed_mode_applies_to_own_counters() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::decrement_own(RuntimeOrigin::signed(1), 10));
+		assert_eq!(CountersByAccount::<Test>::get(1), Some(-10));
+		assert_noop!(
+			CustomPallet::decrement_own(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::CounterValueBelowMin
+		);
+	});
+}
+
+#[test]
+fn signed_mode_applies_to_registry_counters() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CustomPallet::create_counter(RuntimeOrigin::signed(1), name(b"votes"), 5, -11),
+			Error::<Test>::CounterValueBelowMin
+		);
+		assert_ok!(CustomPallet::create_counter(RuntimeOrigin::signed(1), name(b"votes"), 5, -5));
+
+		assert_ok!(CustomPallet::decrement_counter(RuntimeOrigin::signed(1), 0, 5));
+		assert_eq!(Counters::<Test>::get(0).map(|info| info.value), Some(-10));
+		assert_noop!(
+			CustomPallet::decrement_counter(RuntimeOrigin::signed(1), 0, 1),
+			Error::<Test>::CounterValueBelowMin
+		);
+	});
+}
+
+#[test]
+fn signed_mode_applies_to_operations() {
+	new_test_ext().execute_with(|| {
+		use CounterOp::*;
+
+		let ops = |operations: &[CounterOp<i64>]| operations.to_vec().try_into().unwrap();
+		assert_ok!(CustomPallet::apply_operations(RuntimeOrigin::root(), ops(&[Set(-5)])));
+		assert_eq!(CounterValue::<Test>::get(), Some(-5));
+		assert_noop!(
+			CustomPallet::apply_operations(RuntimeOrigin::root(), ops(&[Set(-11)])),
+			Error::<Test>::CounterValueBelowMin
+		);
+		assert_noop!(
+			CustomPallet::apply_operations(RuntimeOrigin::signed(1), ops(&[Increment(-1)])),
+			Error::<Test>::NegativeAmount
+		);
+	});
+}
+
+#[test]
+fn zero_min_keeps_signed_counters_non_negative() {
+	CounterMinValue::set(0);
+	let genesis = GenesisConfig::<Test> { authorized_keys: vec![1], ..Default::default() };
+	new_test_ext_with_genesis(genesis).execute_with(|| {
+		use CounterOp::*;
+
+		assert_noop!(
+			CustomPallet::decrement(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::CounterValueBelowZero
+		);
+		assert_noop!(
+			CustomPallet::apply_operations(
+				RuntimeOrigin::root(),
+				vec![Set(-1)].try_into().unwrap()
+			),
+			Error::<Test>::CounterValueBelowZero
+		);
+		assert_noop!(
+			CustomPallet::submit_external_value(RuntimeOrigin::signed(1), -1),
+			Error::<Test>::CounterValueBelowZero
+		);
+	});
+}
+
+#[test]
+fn negative_amounts_are_rejected() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CustomPallet::increment(RuntimeOrigin::signed(1), -1),
+			Error::<Test>::NegativeAmount
+		);
+		assert_noop!(
+			CustomPallet::decrement(RuntimeOrigin::signed(1), -1),
+			Error::<Test>::NegativeAmount
+		);
+		assert_noop!(
+			CustomPallet::increment_own(RuntimeOrigin::signed(1), -1),
+			Error::<Test>::NegativeAmount
+		);
+		assert_noop!(
+			CustomPallet::decrement_own(RuntimeOrigin::signed(1), -1),
+			Error::<Test>::NegativeAmount
+		);
+		assert_noop!(
+			CustomPallet::increment_from_remote(
+				RuntimeOrigin::signed(PARA_ACCOUNT_OFFSET + 2000),
+				2000.into(),
+				-1
+			),
+			Error::<Test>::NegativeAmount
+		);
+	});
+}
+
+#[test]
+fn negative_unsigned_increments_are_invalid() {
+	new_test_ext().execute_with(|| {
+		let payload = IncrementPayload {
+			public: UintAuthorityId(9),
+			amount: -1,
+			nonce: 0,
+			genesis_hash: System::block_hash(0),
+		};
+		let signature = TestSignature(9, payload.encode());
+		let call = crate::Call::increment_unsigned { payload, signature };
+
+		assert_eq!(
+			CustomPallet::validate_unsigned(TransactionSource::External, &call),
+			Err(ValidityError::NegativeAmount.into())
+		);
+	});
+}
+
+#[test]
+fn check_counter_bounds_follows_a_negative_min() {
+	new_test_ext().execute_with(|| {
+		CounterValue::<Test>::put(4);
+		CounterMinValue::set(-3);
+		assert_ok!(check_bounds(crate::Call::decrement { amount_to_decrement: 7 }));
+		assert_eq!(
+			check_bounds(crate::Call::decrement { amount_to_decrement: 8 }),
+			Err(ValidityError::CounterValueBelowMin.into())
+		);
+	});
+}
+
+#[test]
+fn check_counter_bounds_rejects_negative_amounts() {
+	new_test_ext().execute_with(|| {
+		CounterValue::<Test>::put(4);
+		assert_eq!(
+			check_bounds(crate::Call::increment { amount_to_increment: -1 }),
+			Err(ValidityError::NegativeAmount.into())
+		);
+		assert_eq!(
+			check_bounds(crate::Call::decrement { amount_to_decrement: -1 }),
+			Err(ValidityError::NegativeAmount.into())
+		);
+	});
+}
+
+#[test]
+#[should_panic(expected = "the initial counter value is below `CounterMinValue`")]
+fn genesis_config_rejects_values_below_min() {
+	let genesis = GenesisConfig::<Test> { counter_value: Some(-11), ..Default::default() };
+	new_test_ext_with_genesis(genesis);
+}
@@ -0,0 +1,83 @@
+//! Traits through which other pallets use the global counter of the custom pallet.
+//!
+//! A pallet depending on the counter declares an associated type bound by [`CounterInspect`] or
+//! [`CounterMutate`] in its own `Config`, instead of requiring `pallet_custom::Config`. Runtimes
+//! set it to [`Pallet`], and mocks that do not need a counter may set it to `()`.
+
+use crate::{Config, Pallet};
+use frame_support::storage::with_storage_layer;
+use sp_runtime::{traits::Zero, DispatchResult};
+
+/// Read the global counter and the interactions of accounts with it.
+pub trait CounterInspect<AccountId, CounterValue> {
+	/// The current value of the global counter.
+	fn counter_value() -> CounterValue;
+
+	/// The maximum value of the global counter.
+	fn max_value() -> CounterValue;
+
+	/// The number of times `who` incremented or decremented the global counter.
+	fn interactions_of(who: &AccountId) -> u32;
+}
+
+/// Change the global counter on behalf of an account.
+///
+/// Changes are subject to the same checks as the [`Pallet::increment`] and [`Pallet::decrement`]
+/// calls of `who`: the bounds of the counter, the rate limit and the deposit for the first
+/// interaction. A failed change leaves the storage untouched.
+pub trait CounterMutate<AccountId, CounterValue>: CounterInspect<AccountId, CounterValue> {
+	/// Increment the global counter by `amount` on behalf of `who`.
+	fn increment(who: &AccountId, amount: CounterValue) -> DispatchResult;
+
+	/// Decrement the global counter by `amount` on behalf of `who`.
+	fn decrement(who: &AccountId, amount: CounterValue) -> DispatchResult;
+}
+
+impl<T: Config> CounterInspect<T::AccountId, T::CounterValue> for Pallet<T> {
+	fn counter_value() -> T::CounterValue {
+		Pallet::<T>::counter_value()
+	}
+
+	fn max_value() -> T::CounterValue {
+		Pallet::<T>::max_value()
+	}
+
+	fn interactions_of(who: &T::AccountId) -> u32 {
+		Pallet::<T>::interactions_of(who)
+	}
+}
+
+impl<T: Config> CounterMutate<T::AccountId, T::CounterValue> for Pallet<T> {
+	fn increment(who: &T::AccountId, amount: T::CounterValue) -> DispatchResult {
+		with_storage_layer(|| Pallet::<T>::do_increment(who.clone(), amount, true))
+	}
+
+	fn decrement(who: &T::AccountId, amount: T::CounterValue) -> DispatchResult {
+		with_storage_layer(|| Pallet::<T>::do_decrement(who.clone(), amount))
+	}
+}
+
+/// A counter that always reads zero and ignores every change.
+impl<AccountId, CounterValue: Zero> CounterInspect<AccountId, CounterValue> for () {
+	fn counter_value() -> CounterValue {
+		Zero::zero()
+	}
+
+	fn max_value() -> CounterValue {
+		Zero::zero()
+	}
+
+	fn interactions_of(_who: &AccountId) -> u32 {
+		0
+	}
+}
+
+impl<AccountId, CounterValue: Zero> CounterMutate<AccountId, CounterValue> for () {
+	fn increment(_who: &AccountId, _amount: CounterValue) -> DispatchResult {
+		Ok(())
+	}
+
+	fn decrement(_who: &AccountId, _amount: CounterValue) -> DispatchResult {
+		Ok(())
+	}
+}
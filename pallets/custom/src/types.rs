@@ -0,0 +1,110 @@
+//! Types used by the custom pallet.
+
+use crate::{BalanceOf, Config};
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{
+	pallet_prelude::RuntimeDebug, BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound,
+	RuntimeDebugNoBound,
+};
+use frame_system::{
+	offchain::{SignedPayload, SigningTypes},
+	pallet_prelude::BlockNumberFor,
+};
+use scale_info::TypeInfo;
+
+/// Identifier of a counter in the registry.
+pub type CounterId = u32;
+
+/// A named counter in the registry.
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[codec(mel_bound(T: Config))]
+#[scale_info(skip_type_params(T))]
+pub struct CounterInfo<T: Config> {
+	/// The account allowed to mutate, transfer and destroy the counter.
+	pub owner: T::AccountId,
+	/// Human readable name of the counter.
+	pub name: BoundedVec<u8, T::MaxNameLength>,
+	/// The current value of the counter.
+	pub value: T::CounterValue,
+	/// The maximum value the counter can hold.
+	pub max: T::CounterValue,
+	/// The block at which the counter was created.
+	pub created_at: BlockNumberFor<T>,
+	/// The deposit held from the owner for this counter.
+	pub deposit: BalanceOf<T>,
+}
+
+/// The interactions of an account, or of a sibling parachain, with the global counter.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct InteractionRecord<BlockNumber> {
+	/// The number of times the account or parachain changed the counter.
+	pub count: u32,
+	/// The block of the latest interaction.
+	pub last_block: BlockNumber,
+}
+
+/// A single change of the global counter within [`Pallet::apply_operations`](crate::Pallet).
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum CounterOp<CounterValue> {
+	/// Increase the counter by the given amount.
+	Increment(CounterValue),
+	/// Decrease the counter by the given amount.
+	Decrement(CounterValue),
+	/// Set the counter to the given value.
+	Set(CounterValue),
+}
+
+/// An increment of the global counter signed by an account, to be submitted through
+/// [`Pallet::increment_unsigned`](crate::Pallet::increment_unsigned).
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct IncrementPayload<Public, CounterValue, Hash> {
+	/// The key of the account incrementing the counter.
+	pub public: Public,
+	/// The amount to increment the counter by.
+	pub amount: CounterValue,
+	/// The next nonce of the account in [`UnsignedNonces`](crate::UnsignedNonces).
+	pub nonce: u32,
+	/// The genesis hash of the chain the increment is meant for, so it cannot be replayed on
+	/// another chain.
+	pub genesis_hash: Hash,
+}
+
+impl<T: Config + SigningTypes> SignedPayload<T>
+	for IncrementPayload<T::Public, T::CounterValue, T::Hash>
+{
+	fn public(&self) -> T::Public {
+		self.public.clone()
+	}
+}
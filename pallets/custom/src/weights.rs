@@ -0,0 +1,1061 @@
+
+//! Autogenerated weights for `pallet_custom`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 49.0.0
+//! DATE: 2026-10-18, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor @ 2.10GHz`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: 1024
+
+// Executed Command:
+// frame-omni-bencher
+// v1
+// benchmark
+// pallet
+// --runtime
+// target/release/wbuild/custom-runtime/custom_runtime.compact.compressed.wasm
+// --pallet
+// pallet_custom
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --template
+// .maintain/frame-weight-template.hbs
+// --output
+// pallets/custom/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_custom`.
+pub trait WeightInfo {
+	fn set_counter_value() -> Weight;
+	fn increment() -> Weight;
+	fn increment_existing_interactor() -> Weight;
+	fn decrement() -> Weight;
+	fn decrement_existing_interactor() -> Weight;
+	fn increment_own() -> Weight;
+	fn decrement_own() -> Weight;
+	fn reset_own() -> Weight;
+	fn create_counter() -> Weight;
+	fn increment_unsigned() -> Weight;
+	fn increment_counter() -> Weight;
+	fn decrement_counter() -> Weight;
+	fn transfer_counter_ownership() -> Weight;
+	fn accept_counter_ownership() -> Weight;
+	fn destroy_counter() -> Weight;
+	fn clear_interactions() -> Weight;
+	fn add_to_allow_list() -> Weight;
+	fn remove_from_allow_list() -> Weight;
+	fn set_max_value() -> Weight;
+	fn apply_operations(n: u32, ) -> Weight;
+	fn reset_counter() -> Weight;
+	fn schedule_counter_value() -> Weight;
+	fn cancel_scheduled_counter_value() -> Weight;
+	fn schedule_reset() -> Weight;
+	fn cancel_scheduled_reset() -> Weight;
+	fn on_initialize_reset() -> Weight;
+	fn add_authorized_key() -> Weight;
+	fn remove_authorized_key() -> Weight;
+	fn submit_external_value() -> Weight;
+	fn increment_from_remote() -> Weight;
+}
+
+/// Weights for `pallet_custom` measured on the sample runtime.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `CustomPallet::MaxValueOverride` (r:1 w:0)
+	/// Proof: `CustomPallet::MaxValueOverride` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterHistory` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterHistory` (`max_values`: Some(1), `max_size`: Some(1202), added: 1697, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterValue` (r:0 w:1)
+	/// Proof: `CustomPallet::CounterValue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn set_counter_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1233`
+		//  Estimated: `2687`
+		// Minimum execution time: 21_188_000 picoseconds.
+		Weight::from_parts(26_969_000, 0)
+			.saturating_add(Weight::from_parts(0, 2687))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `CustomPallet::RateLimits` (r:1 w:1)
+	/// Proof: `CustomPallet::RateLimits` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterValue` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterValue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::MaxValueOverride` (r:1 w:0)
+	/// Proof: `CustomPallet::MaxValueOverride` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterHistory` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterHistory` (`max_values`: Some(1), `max_size`: Some(1202), added: 1697, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::UserInteractions` (r:1 w:1)
+	/// Proof: `CustomPallet::UserInteractions` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::TopInteractors` (r:1 w:1)
+	/// Proof: `CustomPallet::TopInteractors` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	fn increment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1739`
+		//  Estimated: `3593`
+		// Minimum execution time: 90_162_000 picoseconds.
+		Weight::from_parts(110_268_000, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: `CustomPallet::RateLimits` (r:1 w:1)
+	/// Proof: `CustomPallet::RateLimits` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterValue` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterValue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::MaxValueOverride` (r:1 w:0)
+	/// Proof: `CustomPallet::MaxValueOverride` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterHistory` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterHistory` (`max_values`: Some(1), `max_size`: Some(1202), added: 1697, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::UserInteractions` (r:1 w:1)
+	/// Proof: `CustomPallet::UserInteractions` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::TopInteractors` (r:1 w:1)
+	/// Proof: `CustomPallet::TopInteractors` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	fn increment_existing_interactor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1801`
+		//  Estimated: `3521`
+		// Minimum execution time: 44_102_000 picoseconds.
+		Weight::from_parts(78_308_000, 0)
+			.saturating_add(Weight::from_parts(0, 3521))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `CustomPallet::RateLimits` (r:1 w:1)
+	/// Proof: `CustomPallet::RateLimits` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterValue` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterValue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterHistory` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterHistory` (`max_values`: Some(1), `max_size`: Some(1202), added: 1697, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::UserInteractions` (r:1 w:1)
+	/// Proof: `CustomPallet::UserInteractions` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::TopInteractors` (r:1 w:1)
+	/// Proof: `CustomPallet::TopInteractors` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	fn decrement() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1739`
+		//  Estimated: `3593`
+		// Minimum execution time: 76_321_000 picoseconds.
+		Weight::from_parts(113_084_000, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: `CustomPallet::RateLimits` (r:1 w:1)
+	/// Proof: `CustomPallet::RateLimits` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterValue` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterValue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterHistory` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterHistory` (`max_values`: Some(1), `max_size`: Some(1202), added: 1697, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::UserInteractions` (r:1 w:1)
+	/// Proof: `CustomPallet::UserInteractions` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::TopInteractors` (r:1 w:1)
+	/// Proof: `CustomPallet::TopInteractors` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	fn decrement_existing_interactor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1801`
+		//  Estimated: `3521`
+		// Minimum execution time: 34_123_000 picoseconds.
+		Weight::from_parts(44_166_000, 0)
+			.saturating_add(Weight::from_parts(0, 3521))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `CustomPallet::CountersByAccount` (r:1 w:1)
+	/// Proof: `CustomPallet::CountersByAccount` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	fn increment_own() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5`
+		//  Estimated: `3586`
+		// Minimum execution time: 68_077_000 picoseconds.
+		Weight::from_parts(84_832_000, 0)
+			.saturating_add(Weight::from_parts(0, 3586))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `CustomPallet::CountersByAccount` (r:1 w:1)
+	/// Proof: `CustomPallet::CountersByAccount` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn decrement_own() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `86`
+		//  Estimated: `3521`
+		// Minimum execution time: 13_564_000 picoseconds.
+		Weight::from_parts(19_827_000, 0)
+			.saturating_add(Weight::from_parts(0, 3521))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CountersByAccount` (r:0 w:1)
+	/// Proof: `CustomPallet::CountersByAccount` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn reset_own() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `55`
+		//  Estimated: `3586`
+		// Minimum execution time: 61_122_000 picoseconds.
+		Weight::from_parts(74_430_000, 0)
+			.saturating_add(Weight::from_parts(0, 3586))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `CustomPallet::NextCounterId` (r:1 w:1)
+	/// Proof: `CustomPallet::NextCounterId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::Counters` (r:0 w:1)
+	/// Proof: `CustomPallet::Counters` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	fn create_counter() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5`
+		//  Estimated: `3586`
+		// Minimum execution time: 44_343_000 picoseconds.
+		Weight::from_parts(69_073_000, 0)
+			.saturating_add(Weight::from_parts(0, 3586))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::UnsignedNonces` (r:1 w:1)
+	/// Proof: `CustomPallet::UnsignedNonces` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::RateLimits` (r:1 w:1)
+	/// Proof: `CustomPallet::RateLimits` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::UserInteractions` (r:1 w:1)
+	/// Proof: `CustomPallet::UserInteractions` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterValue` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterValue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::MaxValueOverride` (r:1 w:0)
+	/// Proof: `CustomPallet::MaxValueOverride` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::UnsignedIncrementsInBlock` (r:1 w:1)
+	/// Proof: `CustomPallet::UnsignedIncrementsInBlock` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterHistory` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterHistory` (`max_values`: Some(1), `max_size`: Some(1202), added: 1697, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::TopInteractors` (r:1 w:1)
+	/// Proof: `CustomPallet::TopInteractors` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	fn increment_unsigned() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1645`
+		//  Estimated: `3521`
+		// Minimum execution time: 144_206_000 picoseconds.
+		Weight::from_parts(192_082_000, 0)
+			.saturating_add(Weight::from_parts(0, 3521))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: `CustomPallet::Counters` (r:1 w:1)
+	/// Proof: `CustomPallet::Counters` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	fn increment_counter() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `168`
+		//  Estimated: `3578`
+		// Minimum execution time: 12_433_000 picoseconds.
+		Weight::from_parts(20_368_000, 0)
+			.saturating_add(Weight::from_parts(0, 3578))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `CustomPallet::Counters` (r:1 w:1)
+	/// Proof: `CustomPallet::Counters` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	fn decrement_counter() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `168`
+		//  Estimated: `3578`
+		// Minimum execution time: 12_718_000 picoseconds.
+		Weight::from_parts(15_903_000, 0)
+			.saturating_add(Weight::from_parts(0, 3578))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `CustomPallet::Counters` (r:1 w:0)
+	/// Proof: `CustomPallet::Counters` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::PendingCounterOwners` (r:0 w:1)
+	/// Proof: `CustomPallet::PendingCounterOwners` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn transfer_counter_ownership() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `168`
+		//  Estimated: `3578`
+		// Minimum execution time: 15_453_000 picoseconds.
+		Weight::from_parts(23_446_000, 0)
+			.saturating_add(Weight::from_parts(0, 3578))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `CustomPallet::PendingCounterOwners` (r:1 w:1)
+	/// Proof: `CustomPallet::PendingCounterOwners` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::Counters` (r:1 w:1)
+	/// Proof: `CustomPallet::Counters` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn accept_counter_ownership() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `390`
+		//  Estimated: `6182`
+		// Minimum execution time: 82_987_000 picoseconds.
+		Weight::from_parts(108_426_000, 0)
+			.saturating_add(Weight::from_parts(0, 6182))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `CustomPallet::Counters` (r:1 w:1)
+	/// Proof: `CustomPallet::Counters` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::PendingCounterOwners` (r:0 w:1)
+	/// Proof: `CustomPallet::PendingCounterOwners` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn destroy_counter() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `223`
+		//  Estimated: `3586`
+		// Minimum execution time: 53_478_000 picoseconds.
+		Weight::from_parts(64_509_000, 0)
+			.saturating_add(Weight::from_parts(0, 3586))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `CustomPallet::RateLimits` (r:1 w:1)
+	/// Proof: `CustomPallet::RateLimits` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::UserInteractions` (r:1 w:1)
+	/// Proof: `CustomPallet::UserInteractions` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::TopInteractors` (r:1 w:1)
+	/// Proof: `CustomPallet::TopInteractors` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	fn clear_interactions() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `603`
+		//  Estimated: `3586`
+		// Minimum execution time: 58_792_000 picoseconds.
+		Weight::from_parts(83_069_000, 0)
+			.saturating_add(Weight::from_parts(0, 3586))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `CustomPallet::AllowList` (r:1 w:1)
+	/// Proof: `CustomPallet::AllowList` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn add_to_allow_list() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5`
+		//  Estimated: `3513`
+		// Minimum execution time: 10_124_000 picoseconds.
+		Weight::from_parts(16_194_000, 0)
+			.saturating_add(Weight::from_parts(0, 3513))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `CustomPallet::AllowList` (r:1 w:1)
+	/// Proof: `CustomPallet::AllowList` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn remove_from_allow_list() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `78`
+		//  Estimated: `3513`
+		// Minimum execution time: 11_955_000 picoseconds.
+		Weight::from_parts(18_129_000, 0)
+			.saturating_add(Weight::from_parts(0, 3513))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `CustomPallet::CounterValue` (r:1 w:0)
+	/// Proof: `CustomPallet::CounterValue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::MaxValueOverride` (r:0 w:1)
+	/// Proof: `CustomPallet::MaxValueOverride` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn set_max_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `36`
+		//  Estimated: `1493`
+		// Minimum execution time: 9_363_000 picoseconds.
+		Weight::from_parts(14_129_000, 0)
+			.saturating_add(Weight::from_parts(0, 1493))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `CustomPallet::RateLimits` (r:1 w:1)
+	/// Proof: `CustomPallet::RateLimits` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::MaxValueOverride` (r:1 w:0)
+	/// Proof: `CustomPallet::MaxValueOverride` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterValue` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterValue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterHistory` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterHistory` (`max_values`: Some(1), `max_size`: Some(1202), added: 1697, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::UserInteractions` (r:1 w:1)
+	/// Proof: `CustomPallet::UserInteractions` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::TopInteractors` (r:1 w:1)
+	/// Proof: `CustomPallet::TopInteractors` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 16]`.
+	fn apply_operations(_n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1769`
+		//  Estimated: `3593`
+		// Minimum execution time: 62_008_000 picoseconds.
+		Weight::from_parts(101_010_175, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: `CustomPallet::CounterHistory` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterHistory` (`max_values`: Some(1), `max_size`: Some(1202), added: 1697, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterValue` (r:0 w:1)
+	/// Proof: `CustomPallet::CounterValue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn reset_counter() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1261`
+		//  Estimated: `2687`
+		// Minimum execution time: 14_657_000 picoseconds.
+		Weight::from_parts(20_429_000, 0)
+			.saturating_add(Weight::from_parts(0, 2687))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(10463), added: 12938, mode: `MaxEncodedLen`)
+	fn schedule_counter_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `13928`
+		// Minimum execution time: 19_600_000 picoseconds.
+		Weight::from_parts(29_771_000, 0)
+			.saturating_add(Weight::from_parts(0, 13928))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(10463), added: 12938, mode: `MaxEncodedLen`)
+	fn cancel_scheduled_counter_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `163`
+		//  Estimated: `13928`
+		// Minimum execution time: 25_116_000 picoseconds.
+		Weight::from_parts(31_960_000, 0)
+			.saturating_add(Weight::from_parts(0, 13928))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(10463), added: 12938, mode: `MaxEncodedLen`)
+	fn schedule_reset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `13928`
+		// Minimum execution time: 21_344_000 picoseconds.
+		Weight::from_parts(28_604_000, 0)
+			.saturating_add(Weight::from_parts(0, 13928))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(10463), added: 12938, mode: `MaxEncodedLen`)
+	fn cancel_scheduled_reset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `163`
+		//  Estimated: `13928`
+		// Minimum execution time: 20_156_000 picoseconds.
+		Weight::from_parts(25_731_000, 0)
+			.saturating_add(Weight::from_parts(0, 13928))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `CustomPallet::CounterHistory` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterHistory` (`max_values`: Some(1), `max_size`: Some(1202), added: 1697, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterValue` (r:0 w:1)
+	/// Proof: `CustomPallet::CounterValue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn on_initialize_reset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1261`
+		//  Estimated: `2687`
+		// Minimum execution time: 10_782_000 picoseconds.
+		Weight::from_parts(12_607_000, 0)
+			.saturating_add(Weight::from_parts(0, 2687))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `CustomPallet::AuthorizedKeys` (r:1 w:1)
+	/// Proof: `CustomPallet::AuthorizedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn add_authorized_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5`
+		//  Estimated: `3513`
+		// Minimum execution time: 9_171_000 picoseconds.
+		Weight::from_parts(15_096_000, 0)
+			.saturating_add(Weight::from_parts(0, 3513))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `CustomPallet::AuthorizedKeys` (r:1 w:1)
+	/// Proof: `CustomPallet::AuthorizedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn remove_authorized_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `78`
+		//  Estimated: `3513`
+		// Minimum execution time: 12_447_000 picoseconds.
+		Weight::from_parts(17_290_000, 0)
+			.saturating_add(Weight::from_parts(0, 3513))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `CustomPallet::AuthorizedKeys` (r:1 w:0)
+	/// Proof: `CustomPallet::AuthorizedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::MaxValueOverride` (r:1 w:0)
+	/// Proof: `CustomPallet::MaxValueOverride` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterHistory` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterHistory` (`max_values`: Some(1), `max_size`: Some(1202), added: 1697, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterValue` (r:0 w:1)
+	/// Proof: `CustomPallet::CounterValue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn submit_external_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1303`
+		//  Estimated: `3513`
+		// Minimum execution time: 17_490_000 picoseconds.
+		Weight::from_parts(22_780_000, 0)
+			.saturating_add(Weight::from_parts(0, 3513))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `CustomPallet::CounterValue` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterValue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::MaxValueOverride` (r:1 w:0)
+	/// Proof: `CustomPallet::MaxValueOverride` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterHistory` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterHistory` (`max_values`: Some(1), `max_size`: Some(1202), added: 1697, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::ParaInteractions` (r:1 w:1)
+	/// Proof: `CustomPallet::ParaInteractions` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	fn increment_from_remote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1273`
+		//  Estimated: `3485`
+		// Minimum execution time: 15_721_000 picoseconds.
+		Weight::from_parts(22_309_000, 0)
+			.saturating_add(Weight::from_parts(0, 3485))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `CustomPallet::MaxValueOverride` (r:1 w:0)
+	/// Proof: `CustomPallet::MaxValueOverride` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterHistory` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterHistory` (`max_values`: Some(1), `max_size`: Some(1202), added: 1697, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterValue` (r:0 w:1)
+	/// Proof: `CustomPallet::CounterValue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn set_counter_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1233`
+		//  Estimated: `2687`
+		// Minimum execution time: 21_188_000 picoseconds.
+		Weight::from_parts(26_969_000, 0)
+			.saturating_add(Weight::from_parts(0, 2687))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `CustomPallet::RateLimits` (r:1 w:1)
+	/// Proof: `CustomPallet::RateLimits` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterValue` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterValue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::MaxValueOverride` (r:1 w:0)
+	/// Proof: `CustomPallet::MaxValueOverride` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterHistory` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterHistory` (`max_values`: Some(1), `max_size`: Some(1202), added: 1697, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::UserInteractions` (r:1 w:1)
+	/// Proof: `CustomPallet::UserInteractions` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::TopInteractors` (r:1 w:1)
+	/// Proof: `CustomPallet::TopInteractors` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	fn increment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1739`
+		//  Estimated: `3593`
+		// Minimum execution time: 90_162_000 picoseconds.
+		Weight::from_parts(110_268_000, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(7))
+	}
+	/// Storage: `CustomPallet::RateLimits` (r:1 w:1)
+	/// Proof: `CustomPallet::RateLimits` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterValue` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterValue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::MaxValueOverride` (r:1 w:0)
+	/// Proof: `CustomPallet::MaxValueOverride` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterHistory` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterHistory` (`max_values`: Some(1), `max_size`: Some(1202), added: 1697, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::UserInteractions` (r:1 w:1)
+	/// Proof: `CustomPallet::UserInteractions` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::TopInteractors` (r:1 w:1)
+	/// Proof: `CustomPallet::TopInteractors` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	fn increment_existing_interactor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1801`
+		//  Estimated: `3521`
+		// Minimum execution time: 44_102_000 picoseconds.
+		Weight::from_parts(78_308_000, 0)
+			.saturating_add(Weight::from_parts(0, 3521))
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+	/// Storage: `CustomPallet::RateLimits` (r:1 w:1)
+	/// Proof: `CustomPallet::RateLimits` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterValue` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterValue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterHistory` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterHistory` (`max_values`: Some(1), `max_size`: Some(1202), added: 1697, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::UserInteractions` (r:1 w:1)
+	/// Proof: `CustomPallet::UserInteractions` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::TopInteractors` (r:1 w:1)
+	/// Proof: `CustomPallet::TopInteractors` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	fn decrement() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1739`
+		//  Estimated: `3593`
+		// Minimum execution time: 76_321_000 picoseconds.
+		Weight::from_parts(113_084_000, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(7))
+	}
+	/// Storage: `CustomPallet::RateLimits` (r:1 w:1)
+	/// Proof: `CustomPallet::RateLimits` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterValue` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterValue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterHistory` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterHistory` (`max_values`: Some(1), `max_size`: Some(1202), added: 1697, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::UserInteractions` (r:1 w:1)
+	/// Proof: `CustomPallet::UserInteractions` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::TopInteractors` (r:1 w:1)
+	/// Proof: `CustomPallet::TopInteractors` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	fn decrement_existing_interactor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1801`
+		//  Estimated: `3521`
+		// Minimum execution time: 34_123_000 picoseconds.
+		Weight::from_parts(44_166_000, 0)
+			.saturating_add(Weight::from_parts(0, 3521))
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+	/// Storage: `CustomPallet::CountersByAccount` (r:1 w:1)
+	/// Proof: `CustomPallet::CountersByAccount` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	fn increment_own() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5`
+		//  Estimated: `3586`
+		// Minimum execution time: 68_077_000 picoseconds.
+		Weight::from_parts(84_832_000, 0)
+			.saturating_add(Weight::from_parts(0, 3586))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `CustomPallet::CountersByAccount` (r:1 w:1)
+	/// Proof: `CustomPallet::CountersByAccount` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn decrement_own() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `86`
+		//  Estimated: `3521`
+		// Minimum execution time: 13_564_000 picoseconds.
+		Weight::from_parts(19_827_000, 0)
+			.saturating_add(Weight::from_parts(0, 3521))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CountersByAccount` (r:0 w:1)
+	/// Proof: `CustomPallet::CountersByAccount` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn reset_own() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `55`
+		//  Estimated: `3586`
+		// Minimum execution time: 61_122_000 picoseconds.
+		Weight::from_parts(74_430_000, 0)
+			.saturating_add(Weight::from_parts(0, 3586))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `CustomPallet::NextCounterId` (r:1 w:1)
+	/// Proof: `CustomPallet::NextCounterId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::Counters` (r:0 w:1)
+	/// Proof: `CustomPallet::Counters` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	fn create_counter() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5`
+		//  Estimated: `3586`
+		// Minimum execution time: 44_343_000 picoseconds.
+		Weight::from_parts(69_073_000, 0)
+			.saturating_add(Weight::from_parts(0, 3586))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::UnsignedNonces` (r:1 w:1)
+	/// Proof: `CustomPallet::UnsignedNonces` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::RateLimits` (r:1 w:1)
+	/// Proof: `CustomPallet::RateLimits` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::UserInteractions` (r:1 w:1)
+	/// Proof: `CustomPallet::UserInteractions` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterValue` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterValue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::MaxValueOverride` (r:1 w:0)
+	/// Proof: `CustomPallet::MaxValueOverride` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::UnsignedIncrementsInBlock` (r:1 w:1)
+	/// Proof: `CustomPallet::UnsignedIncrementsInBlock` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterHistory` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterHistory` (`max_values`: Some(1), `max_size`: Some(1202), added: 1697, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::TopInteractors` (r:1 w:1)
+	/// Proof: `CustomPallet::TopInteractors` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	fn increment_unsigned() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1645`
+		//  Estimated: `3521`
+		// Minimum execution time: 144_206_000 picoseconds.
+		Weight::from_parts(192_082_000, 0)
+			.saturating_add(Weight::from_parts(0, 3521))
+			.saturating_add(RocksDbWeight::get().reads(9))
+			.saturating_add(RocksDbWeight::get().writes(7))
+	}
+	/// Storage: `CustomPallet::Counters` (r:1 w:1)
+	/// Proof: `CustomPallet::Counters` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	fn increment_counter() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `168`
+		//  Estimated: `3578`
+		// Minimum execution time: 12_433_000 picoseconds.
+		Weight::from_parts(20_368_000, 0)
+			.saturating_add(Weight::from_parts(0, 3578))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `CustomPallet::Counters` (r:1 w:1)
+	/// Proof: `CustomPallet::Counters` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	fn decrement_counter() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `168`
+		//  Estimated: `3578`
+		// Minimum execution time: 12_718_000 picoseconds.
+		Weight::from_parts(15_903_000, 0)
+			.saturating_add(Weight::from_parts(0, 3578))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `CustomPallet::Counters` (r:1 w:0)
+	/// Proof: `CustomPallet::Counters` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::PendingCounterOwners` (r:0 w:1)
+	/// Proof: `CustomPallet::PendingCounterOwners` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn transfer_counter_ownership() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `168`
+		//  Estimated: `3578`
+		// Minimum execution time: 15_453_000 picoseconds.
+		Weight::from_parts(23_446_000, 0)
+			.saturating_add(Weight::from_parts(0, 3578))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `CustomPallet::PendingCounterOwners` (r:1 w:1)
+	/// Proof: `CustomPallet::PendingCounterOwners` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::Counters` (r:1 w:1)
+	/// Proof: `CustomPallet::Counters` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn accept_counter_ownership() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `390`
+		//  Estimated: `6182`
+		// Minimum execution time: 82_987_000 picoseconds.
+		Weight::from_parts(108_426_000, 0)
+			.saturating_add(Weight::from_parts(0, 6182))
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+	/// Storage: `CustomPallet::Counters` (r:1 w:1)
+	/// Proof: `CustomPallet::Counters` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::PendingCounterOwners` (r:0 w:1)
+	/// Proof: `CustomPallet::PendingCounterOwners` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn destroy_counter() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `223`
+		//  Estimated: `3586`
+		// Minimum execution time: 53_478_000 picoseconds.
+		Weight::from_parts(64_509_000, 0)
+			.saturating_add(Weight::from_parts(0, 3586))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	/// Storage: `CustomPallet::RateLimits` (r:1 w:1)
+	/// Proof: `CustomPallet::RateLimits` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::UserInteractions` (r:1 w:1)
+	/// Proof: `CustomPallet::UserInteractions` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::TopInteractors` (r:1 w:1)
+	/// Proof: `CustomPallet::TopInteractors` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	fn clear_interactions() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `603`
+		//  Estimated: `3586`
+		// Minimum execution time: 58_792_000 picoseconds.
+		Weight::from_parts(83_069_000, 0)
+			.saturating_add(Weight::from_parts(0, 3586))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	/// Storage: `CustomPallet::AllowList` (r:1 w:1)
+	/// Proof: `CustomPallet::AllowList` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn add_to_allow_list() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5`
+		//  Estimated: `3513`
+		// Minimum execution time: 10_124_000 picoseconds.
+		Weight::from_parts(16_194_000, 0)
+			.saturating_add(Weight::from_parts(0, 3513))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `CustomPallet::AllowList` (r:1 w:1)
+	/// Proof: `CustomPallet::AllowList` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn remove_from_allow_list() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `78`
+		//  Estimated: `3513`
+		// Minimum execution time: 11_955_000 picoseconds.
+		Weight::from_parts(18_129_000, 0)
+			.saturating_add(Weight::from_parts(0, 3513))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `CustomPallet::CounterValue` (r:1 w:0)
+	/// Proof: `CustomPallet::CounterValue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::MaxValueOverride` (r:0 w:1)
+	/// Proof: `CustomPallet::MaxValueOverride` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn set_max_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `36`
+		//  Estimated: `1493`
+		// Minimum execution time: 9_363_000 picoseconds.
+		Weight::from_parts(14_129_000, 0)
+			.saturating_add(Weight::from_parts(0, 1493))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `CustomPallet::RateLimits` (r:1 w:1)
+	/// Proof: `CustomPallet::RateLimits` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::MaxValueOverride` (r:1 w:0)
+	/// Proof: `CustomPallet::MaxValueOverride` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterValue` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterValue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterHistory` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterHistory` (`max_values`: Some(1), `max_size`: Some(1202), added: 1697, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::UserInteractions` (r:1 w:1)
+	/// Proof: `CustomPallet::UserInteractions` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::TopInteractors` (r:1 w:1)
+	/// Proof: `CustomPallet::TopInteractors` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 16]`.
+	fn apply_operations(_n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1769`
+		//  Estimated: `3593`
+		// Minimum execution time: 62_008_000 picoseconds.
+		Weight::from_parts(101_010_175, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(7))
+	}
+	/// Storage: `CustomPallet::CounterHistory` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterHistory` (`max_values`: Some(1), `max_size`: Some(1202), added: 1697, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterValue` (r:0 w:1)
+	/// Proof: `CustomPallet::CounterValue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn reset_counter() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1261`
+		//  Estimated: `2687`
+		// Minimum execution time: 14_657_000 picoseconds.
+		Weight::from_parts(20_429_000, 0)
+			.saturating_add(Weight::from_parts(0, 2687))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(10463), added: 12938, mode: `MaxEncodedLen`)
+	fn schedule_counter_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `13928`
+		// Minimum execution time: 19_600_000 picoseconds.
+		Weight::from_parts(29_771_000, 0)
+			.saturating_add(Weight::from_parts(0, 13928))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(10463), added: 12938, mode: `MaxEncodedLen`)
+	fn cancel_scheduled_counter_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `163`
+		//  Estimated: `13928`
+		// Minimum execution time: 25_116_000 picoseconds.
+		Weight::from_parts(31_960_000, 0)
+			.saturating_add(Weight::from_parts(0, 13928))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(10463), added: 12938, mode: `MaxEncodedLen`)
+	fn schedule_reset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `13928`
+		// Minimum execution time: 21_344_000 picoseconds.
+		Weight::from_parts(28_604_000, 0)
+			.saturating_add(Weight::from_parts(0, 13928))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(10463), added: 12938, mode: `MaxEncodedLen`)
+	fn cancel_scheduled_reset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `163`
+		//  Estimated: `13928`
+		// Minimum execution time: 20_156_000 picoseconds.
+		Weight::from_parts(25_731_000, 0)
+			.saturating_add(Weight::from_parts(0, 13928))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `CustomPallet::CounterHistory` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterHistory` (`max_values`: Some(1), `max_size`: Some(1202), added: 1697, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterValue` (r:0 w:1)
+	/// Proof: `CustomPallet::CounterValue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn on_initialize_reset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1261`
+		//  Estimated: `2687`
+		// Minimum execution time: 10_782_000 picoseconds.
+		Weight::from_parts(12_607_000, 0)
+			.saturating_add(Weight::from_parts(0, 2687))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `CustomPallet::AuthorizedKeys` (r:1 w:1)
+	/// Proof: `CustomPallet::AuthorizedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn add_authorized_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5`
+		//  Estimated: `3513`
+		// Minimum execution time: 9_171_000 picoseconds.
+		Weight::from_parts(15_096_000, 0)
+			.saturating_add(Weight::from_parts(0, 3513))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `CustomPallet::AuthorizedKeys` (r:1 w:1)
+	/// Proof: `CustomPallet::AuthorizedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn remove_authorized_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `78`
+		//  Estimated: `3513`
+		// Minimum execution time: 12_447_000 picoseconds.
+		Weight::from_parts(17_290_000, 0)
+			.saturating_add(Weight::from_parts(0, 3513))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `CustomPallet::AuthorizedKeys` (r:1 w:0)
+	/// Proof: `CustomPallet::AuthorizedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::MaxValueOverride` (r:1 w:0)
+	/// Proof: `CustomPallet::MaxValueOverride` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterHistory` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterHistory` (`max_values`: Some(1), `max_size`: Some(1202), added: 1697, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterValue` (r:0 w:1)
+	/// Proof: `CustomPallet::CounterValue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn submit_external_value() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1303`
+		//  Estimated: `3513`
+		// Minimum execution time: 17_490_000 picoseconds.
+		Weight::from_parts(22_780_000, 0)
+			.saturating_add(Weight::from_parts(0, 3513))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `CustomPallet::CounterValue` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterValue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::MaxValueOverride` (r:1 w:0)
+	/// Proof: `CustomPallet::MaxValueOverride` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::CounterHistory` (r:1 w:1)
+	/// Proof: `CustomPallet::CounterHistory` (`max_values`: Some(1), `max_size`: Some(1202), added: 1697, mode: `MaxEncodedLen`)
+	/// Storage: `CustomPallet::ParaInteractions` (r:1 w:1)
+	/// Proof: `CustomPallet::ParaInteractions` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	fn increment_from_remote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1273`
+		//  Estimated: `3485`
+		// Minimum execution time: 15_721_000 picoseconds.
+		Weight::from_parts(22_309_000, 0)
+			.saturating_add(Weight::from_parts(0, 3485))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+}
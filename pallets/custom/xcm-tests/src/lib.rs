@@ -0,0 +1,87 @@
+//! # Custom Pallet XCM Tests
+//!
+//! Simulates a relay chain with two parachains running [`pallet_custom`], to test the global
+//! counter being incremented through XCM `Transact` messages from a sibling parachain.
+//!
+//! The [`parachain`] runtime converts the `Transact` of a sibling parachain with
+//! [`OriginKind::Native`](xcm::latest::OriginKind::Native) into a
+//! [`cumulus_pallet_xcm::Origin::SiblingParachain`] origin, admitted by
+//! [`parachain::EnsureSiblingParachain`] as the `XcmOrigin` of the pallet.
+
+pub mod parachain;
+pub mod relay_chain;
+
+#[cfg(test)]
+mod tests;
+
+use sp_runtime::BuildStorage;
+use xcm_simulator::{decl_test_network, decl_test_parachain, decl_test_relay_chain, TestExt};
+
+/// The identifier of [`ParaA`].
+pub const PARA_A_ID: u32 = 2000;
+/// The identifier of [`ParaB`].
+pub const PARA_B_ID: u32 = 2001;
+
+decl_test_parachain! {
+	pub struct ParaA {
+		Runtime = parachain::Runtime,
+		XcmpMessageHandler = parachain::MsgQueue,
+		DmpMessageHandler = parachain::MsgQueue,
+		new_ext = para_ext(PARA_A_ID),
+	}
+}
+
+decl_test_parachain! {
+	pub struct ParaB {
+		Runtime = parachain::Runtime,
+		XcmpMessageHandler = parachain::MsgQueue,
+		DmpMessageHandler = parachain::MsgQueue,
+		new_ext = para_ext(PARA_B_ID),
+	}
+}
+
+decl_test_relay_chain! {
+	pub struct Relay {
+		Runtime = relay_chain::Runtime,
+		RuntimeCall = relay_chain::RuntimeCall,
+		RuntimeEvent = relay_chain::RuntimeEvent,
+		XcmConfig = relay_chain::XcmConfig,
+		MessageQueue = relay_chain::MessageQueue,
+		System = relay_chain::System,
+		new_ext = relay_ext(),
+	}
+}
+
+decl_test_network! {
+	pub struct MockNet {
+		relay_chain = Relay,
+		parachains = vec![
+			(PARA_A_ID, ParaA),
+			(PARA_B_ID, ParaB),
+		],
+	}
+}
+
+/// Build the genesis storage of the parachain `para_id`, starting at block 1 so events are
+/// recorded.
+pub fn para_ext(para_id: u32) -> sp_io::TestExternalities {
+	let storage = frame_system::GenesisConfig::<parachain::Runtime>::default()
+		.build_storage()
+		.unwrap();
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| {
+		parachain::System::set_block_number(1);
+		parachain::MsgQueue::set_para_id(para_id.into());
+	});
+	ext
+}
+
+/// Build the genesis storage of the relay chain, starting at block 1 so events are recorded.
+pub fn relay_ext() -> sp_io::TestExternalities {
+	let storage = frame_system::GenesisConfig::<relay_chain::Runtime>::default()
+		.build_storage()
+		.unwrap();
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| relay_chain::System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,261 @@
+//! A parachain runtime running [`pallet_custom`], which sibling parachains may increment through
+//! XCM.
+
+use codec::Encode;
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstU32, EnsureOrigin, EqualPrivilegeOnly, Everything, Get, Nothing},
+	weights::Weight,
+};
+use frame_system::{
+	offchain::{AppCrypto, CreateSignedTransaction, CreateTransactionBase, SigningTypes},
+	EnsureRoot, EnsureSigned,
+};
+use pallet_custom::ParaId;
+use polkadot_parachain_primitives::primitives::Sibling;
+use sp_runtime::{
+	generic::UncheckedExtrinsic,
+	testing::{TestSignature, UintAuthorityId},
+};
+use xcm::latest::prelude::*;
+use xcm_builder::{
+	AllowExplicitUnpaidExecutionFrom, FixedWeightBounds, FrameTransactionalProcessor,
+	ParentIsPreset, SiblingParachainAsNative, SiblingParachainConvertsVia,
+	SovereignSignedViaLocation,
+};
+use xcm_executor::XcmExecutor;
+
+/// The account identifier of the parachain.
+pub type AccountId = u64;
+
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+frame_support::construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		Balances: pallet_balances,
+		Preimage: pallet_preimage,
+		Scheduler: pallet_scheduler,
+		MsgQueue: xcm_simulator::mock_message_queue,
+		CumulusXcm: cumulus_pallet_xcm,
+		CustomPallet: pallet_custom,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Runtime {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Runtime {
+	type AccountStore = System;
+	type RuntimeHoldReason = RuntimeHoldReason;
+}
+
+impl pallet_preimage::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Currency = ();
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	type Consideration = ();
+}
+
+parameter_types! {
+	pub MaximumSchedulerWeight: Weight = Weight::MAX;
+}
+
+impl pallet_scheduler::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type PalletsOrigin = OriginCaller;
+	type RuntimeCall = RuntimeCall;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type ScheduleOrigin = EnsureRoot<AccountId>;
+	type OriginPrivilegeCmp = EqualPrivilegeOnly;
+	type MaxScheduledPerBlock = ConstU32<10>;
+	type WeightInfo = ();
+	type Preimages = Preimage;
+	type BlockNumberProvider = System;
+}
+
+parameter_types! {
+	pub const RelayNetwork: NetworkId = NetworkId::Polkadot;
+	pub UniversalLocation: InteriorLocation =
+		[GlobalConsensus(RelayNetwork::get()), Parachain(MsgQueue::get().into())].into();
+	pub const UnitWeightCost: Weight = Weight::from_parts(1_000, 1_000);
+	pub const MaxInstructions: u32 = 100;
+	pub const MaxAssetsIntoHolding: u32 = 64;
+}
+
+/// Converts the locations of the relay chain and of sibling parachains into their sovereign
+/// accounts.
+pub type LocationToAccountId =
+	(ParentIsPreset<AccountId>, SiblingParachainConvertsVia<Sibling, AccountId>);
+
+/// Converts the origins of `Transact` instructions into local origins: sovereign accounts for
+/// [`OriginKind::SovereignAccount`], and sibling parachain origins for [`OriginKind::Native`].
+pub type XcmOriginToCallOrigin = (
+	SovereignSignedViaLocation<LocationToAccountId, RuntimeOrigin>,
+	SiblingParachainAsNative<cumulus_pallet_xcm::Origin, RuntimeOrigin>,
+);
+
+/// The XCM router of the parachain.
+pub type XcmRouter = crate::ParachainXcmRouter<MsgQueue>;
+
+/// The XCM executor configuration of the parachain.
+///
+/// Messages carry no assets: execution is not charged, as long as the message starts with
+/// `UnpaidExecution`.
+pub struct XcmConfig;
+
+impl xcm_executor::Config for XcmConfig {
+	type RuntimeCall = RuntimeCall;
+	type XcmSender = XcmRouter;
+	type XcmEventEmitter = ();
+	type AssetTransactor = ();
+	type OriginConverter = XcmOriginToCallOrigin;
+	type IsReserve = ();
+	type IsTeleporter = ();
+	type Aliasers = Nothing;
+	type UniversalLocation = UniversalLocation;
+	type Barrier = AllowExplicitUnpaidExecutionFrom<Everything>;
+	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type Trader = ();
+	type ResponseHandler = ();
+	type AssetTrap = ();
+	type AssetLocker = ();
+	type AssetExchanger = ();
+	type AssetClaims = ();
+	type SubscriptionService = ();
+	type PalletInstancesInfo = AllPalletsWithSystem;
+	type MaxAssetsIntoHolding = MaxAssetsIntoHolding;
+	type FeeManager = ();
+	type MessageExporter = ();
+	type UniversalAliases = Nothing;
+	type CallDispatcher = RuntimeCall;
+	type SafeCallFilter = Everything;
+	type TransactionalProcessor = FrameTransactionalProcessor;
+	type HrmpNewChannelOpenRequestHandler = ();
+	type HrmpChannelAcceptedHandler = ();
+	type HrmpChannelClosingHandler = ();
+	type XcmRecorder = ();
+}
+
+impl xcm_simulator::mock_message_queue::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+}
+
+impl cumulus_pallet_xcm::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+}
+
+/// The transactions submitted by the off-chain worker, signed by a [`UintAuthorityId`].
+pub type Extrinsic = UncheckedExtrinsic<AccountId, RuntimeCall, TestSignature, ()>;
+
+impl SigningTypes for Runtime {
+	type Public = UintAuthorityId;
+	type Signature = TestSignature;
+}
+
+impl<LocalCall> CreateTransactionBase<LocalCall> for Runtime
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type Extrinsic = Extrinsic;
+	type RuntimeCall = RuntimeCall;
+}
+
+impl<LocalCall> CreateSignedTransaction<LocalCall> for Runtime
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_signed_transaction<C: AppCrypto<Self::Public, Self::Signature>>(
+		call: RuntimeCall,
+		public: UintAuthorityId,
+		account: AccountId,
+		nonce: Self::Nonce,
+	) -> Option<Extrinsic> {
+		let signature = C::sign(&(&call, nonce).encode(), public)?;
+		Some(Extrinsic::new_signed(call, account, signature, ()))
+	}
+}
+
+/// Signs the off-chain worker transactions with the keys set through
+/// [`UintAuthorityId::set_all_keys`].
+pub struct TestAuthorityId;
+
+impl AppCrypto<UintAuthorityId, TestSignature> for TestAuthorityId {
+	type RuntimeAppPublic = UintAuthorityId;
+	type GenericPublic = UintAuthorityId;
+	type GenericSignature = TestSignature;
+}
+
+/// Admits the origins of sibling parachains, as converted by [`SiblingParachainAsNative`].
+pub struct EnsureSiblingParachain;
+
+impl EnsureOrigin<RuntimeOrigin> for EnsureSiblingParachain {
+	type Success = ParaId;
+
+	fn try_origin(o: RuntimeOrigin) -> Result<ParaId, RuntimeOrigin> {
+		cumulus_pallet_xcm::ensure_sibling_para(o.clone()).map_err(|_| o)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+		Ok(cumulus_pallet_xcm::Origin::SiblingParachain(crate::PARA_A_ID.into()).into())
+	}
+}
+
+parameter_types! {
+	pub const CounterMaxValue: i64 = 10;
+	pub const CounterMinValue: i64 = 0;
+	pub const MaxOpsPerCall: u32 = 4;
+	pub const MaxHistory: u32 = 4;
+	pub const MaxLeaderboard: u32 = 3;
+	pub const MaxNameLength: u32 = 16;
+	pub const CounterDeposit: u64 = 10;
+	pub const InteractionDeposit: u64 = 2;
+	pub const MaxInteractionsPerPeriod: u32 = 3;
+	pub const RatePeriod: u64 = 10;
+	pub const ResetPeriod: Option<u64> = None;
+	pub const ResetInteractions: bool = false;
+	pub const OffchainInterval: u64 = 0;
+	pub const UnsignedPriority: u64 = 100;
+	pub const UnsignedLongevity: u64 = 8;
+	pub const MaxUnsignedPerBlock: u32 = 4;
+}
+
+impl pallet_custom::Config for Runtime {
+	type CounterValue = i64;
+	type CounterMaxValue = CounterMaxValue;
+	type CounterMinValue = CounterMinValue;
+	type MaxOpsPerCall = MaxOpsPerCall;
+	type MaxHistory = MaxHistory;
+	type MaxLeaderboard = MaxLeaderboard;
+	type MaxNameLength = MaxNameLength;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type CounterDeposit = CounterDeposit;
+	type InteractionDeposit = InteractionDeposit;
+	type MaxInteractionsPerPeriod = MaxInteractionsPerPeriod;
+	type RatePeriod = RatePeriod;
+	type ResetPeriod = ResetPeriod;
+	type ResetInteractions = ResetInteractions;
+	type IncrementOrigin = EnsureSigned<AccountId>;
+	type DecrementOrigin = EnsureSigned<AccountId>;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type PalletsOrigin = OriginCaller;
+	type Scheduler = Scheduler;
+	type Preimages = Preimage;
+	type AuthorityId = TestAuthorityId;
+	type OffchainInterval = OffchainInterval;
+	type UnsignedPriority = UnsignedPriority;
+	type UnsignedLongevity = UnsignedLongevity;
+	type MaxUnsignedPerBlock = MaxUnsignedPerBlock;
+	type XcmOrigin = EnsureSiblingParachain;
+	type WeightInfo = ();
+}
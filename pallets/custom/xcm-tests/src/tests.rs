@@ -0,0 +1,177 @@
+use crate::{parachain, MockNet, ParaA, ParaB, Relay, PARA_A_ID, PARA_B_ID};
+use codec::Encode;
+use frame_support::assert_ok;
+use pallet_custom::{CounterValue, Event, ParaInteractions};
+use xcm::latest::prelude::*;
+use xcm_simulator::{mock_message_queue, TestExt};
+
+/// A message dispatching `call` on the receiving parachain with `origin_kind`.
+fn transact(origin_kind: OriginKind, call: parachain::RuntimeCall) -> Xcm<()> {
+	Xcm(vec![
+		UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+		Transact { origin_kind, fallback_max_weight: None, call: call.encode().into() },
+	])
+}
+
+/// A message incrementing the counter of the receiving parachain by `amount` on behalf of
+/// `para_id`.
+fn increment_from_remote(para_id: u32, amount: i64) -> Xcm<()> {
+	transact(
+		OriginKind::Native,
+		pallet_custom::Call::increment_from_remote {
+			para_id: para_id.into(),
+			amount_to_increment: amount,
+		}
+		.into(),
+	)
+}
+
+/// Send `message` from the current parachain to its sibling `para_id`.
+fn send_to_sibling(para_id: u32, message: Xcm<()>) {
+	assert_ok!(send_xcm::<parachain::XcmRouter>((Parent, Parachain(para_id)).into(), message));
+}
+
+/// Whether the current parachain executed a message of a sibling, regardless of the outcome of
+/// its `Transact`.
+fn executed_sibling_message() -> bool {
+	parachain::System::events().iter().any(|record| {
+		matches!(
+			record.event,
+			parachain::RuntimeEvent::MsgQueue(mock_message_queue::Event::Success { .. })
+		)
+	})
+}
+
+fn counter_value() -> Option<i64> {
+	CounterValue::<parachain::Runtime>::get()
+}
+
+fn para_interactions(para_id: u32) -> Option<u32> {
+	ParaInteractions::<parachain::Runtime>::get(pallet_custom::ParaId::from(para_id))
+		.map(|record| record.count)
+}
+
+#[test]
+fn sibling_parachain_increments_the_counter() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| send_to_sibling(PARA_B_ID, increment_from_remote(PARA_A_ID, 3)));
+
+	ParaB::execute_with(|| {
+		assert_eq!(counter_value(), Some(3));
+		assert_eq!(para_interactions(PARA_A_ID), Some(1));
+		parachain::System::assert_has_event(
+			Event::CounterIncrementedFromRemote {
+				counter_value: 3,
+				para_id: PARA_A_ID.into(),
+				incremented_amount: 3,
+			}
+			.into(),
+		);
+	});
+
+	// The counter of the sender is left untouched.
+	ParaA::execute_with(|| assert_eq!(counter_value(), None));
+}
+
+#[test]
+fn interactions_are_recorded_per_parachain() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| {
+		send_to_sibling(PARA_B_ID, increment_from_remote(PARA_A_ID, 1));
+		send_to_sibling(PARA_B_ID, increment_from_remote(PARA_A_ID, 2));
+	});
+	ParaB::execute_with(|| send_to_sibling(PARA_A_ID, increment_from_remote(PARA_B_ID, 4)));
+
+	ParaA::execute_with(|| {
+		assert_eq!(counter_value(), Some(4));
+		assert_eq!(para_interactions(PARA_B_ID), Some(1));
+		assert_eq!(para_interactions(PARA_A_ID), None);
+	});
+	ParaB::execute_with(|| {
+		assert_eq!(counter_value(), Some(3));
+		assert_eq!(para_interactions(PARA_A_ID), Some(2));
+		assert_eq!(para_interactions(PARA_B_ID), None);
+	});
+}
+
+#[test]
+fn sibling_parachain_cannot_increment_on_behalf_of_another() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| send_to_sibling(PARA_B_ID, increment_from_remote(PARA_B_ID, 1)));
+
+	ParaB::execute_with(|| {
+		assert!(executed_sibling_message());
+		assert_eq!(counter_value(), None);
+		assert_eq!(para_interactions(PARA_A_ID), None);
+		assert_eq!(para_interactions(PARA_B_ID), None);
+	});
+}
+
+#[test]
+fn remote_increments_respect_the_counter_bounds() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| {
+		send_to_sibling(PARA_B_ID, increment_from_remote(PARA_A_ID, 8));
+		send_to_sibling(
+			PARA_B_ID,
+			increment_from_remote(PARA_A_ID, parachain::CounterMaxValue::get() - 7),
+		);
+	});
+
+	ParaB::execute_with(|| {
+		assert_eq!(counter_value(), Some(8));
+		assert_eq!(para_interactions(PARA_A_ID), Some(1));
+	});
+}
+
+#[test]
+fn sovereign_account_transacts_are_not_remote_increments() {
+	MockNet::reset();
+
+	let call = pallet_custom::Call::increment_from_remote {
+		para_id: PARA_A_ID.into(),
+		amount_to_increment: 1,
+	};
+	ParaA::execute_with(|| {
+		send_to_sibling(PARA_B_ID, transact(OriginKind::SovereignAccount, call.into()))
+	});
+
+	// The sovereign account of the sibling is a signed origin, not an `XcmOrigin`.
+	ParaB::execute_with(|| {
+		assert!(executed_sibling_message());
+		assert_eq!(counter_value(), None);
+		assert_eq!(para_interactions(PARA_A_ID), None);
+	});
+}
+
+#[test]
+fn relay_chain_cannot_increment_the_counter() {
+	MockNet::reset();
+
+	Relay::execute_with(|| {
+		assert_ok!(send_xcm::<crate::RelayChainXcmRouter>(
+			Parachain(PARA_A_ID).into(),
+			increment_from_remote(PARA_A_ID, 1),
+		));
+	});
+
+	// The relay chain is not converted into any origin of the parachain.
+	ParaA::execute_with(|| {
+		assert!(parachain::System::events().iter().any(|record| matches!(
+			&record.event,
+			parachain::RuntimeEvent::MsgQueue(mock_message_queue::Event::ExecutedDownward {
+				outcome: Outcome::Incomplete {
+					error: InstructionError { error: XcmError::BadOrigin, .. },
+					..
+				},
+				..
+			})
+		)));
+		assert_eq!(counter_value(), None);
+		assert_eq!(para_interactions(PARA_A_ID), None);
+	});
+}
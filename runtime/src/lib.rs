@@ -0,0 +1,466 @@
+//! # Custom Runtime
+//!
+//! A sample runtime wiring [`pallet_custom`] next to `frame_system`, `pallet_balances` and
+//! `pallet_scheduler`, and implementing [`pallet_custom_runtime_api::CounterApi`] so clients can
+//! read the counter state through the runtime API instead of raw storage queries.
+//! It also creates the signed transactions of the pallet's off-chain worker, with the
+//! [`pallet_custom::crypto`] keys of the node.
+//!
+//! The runtime is also built to wasm, so the pallet can be benchmarked with `frame-omni-bencher`
+//! when the `runtime-benchmarks` feature is enabled. It is meant as a reference for integrating
+//! the pallet and as a test bed for its runtime API, not as a production chain.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![recursion_limit = "256"]
+
+extern crate alloc;
+
+#[cfg(test)]
+mod tests;
+
+// Make the wasm binary available.
+#[cfg(feature = "std")]
+include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
+
+use alloc::{borrow::Cow, vec::Vec};
+use codec::Encode;
+use frame_support::{
+	derive_impl,
+	genesis_builder_helper::{build_state, get_preset},
+	parameter_types,
+	traits::{
+		fungible::HoldConsideration, EqualPrivilegeOnly, Get, LinearStoragePrice, VariantCountOf,
+	},
+	weights::Weight,
+};
+use frame_system::{limits::BlockWeights, EnsureRoot, EnsureSigned};
+use sp_api::impl_runtime_apis;
+use sp_core::OpaqueMetadata;
+use sp_runtime::{
+	generic,
+	traits::{BlakeTwo256, Block as BlockT, IdentifyAccount, SaturatedConversion, Verify},
+	transaction_validity::{
+		TransactionLongevity, TransactionPriority, TransactionSource, TransactionValidity,
+	},
+	ApplyExtrinsicResult, ExtrinsicInclusionMode, MultiAddress, MultiSignature, Perbill,
+};
+use sp_version::RuntimeVersion;
+
+/// The signature type used by accounts and transactions.
+pub type Signature = MultiSignature;
+
+/// An account identifier, derived from the signer of a [`Signature`].
+pub type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::AccountId;
+
+/// The balance of an account.
+pub type Balance = u128;
+
+/// The type of the values held by the counter pallet.
+pub type CounterValue = u64;
+
+/// The index of a transaction in an account's history.
+pub type Nonce = u32;
+
+/// The block number type.
+pub type BlockNumber = u32;
+
+/// The address format used to describe the sender of a transaction.
+pub type Address = MultiAddress<AccountId, ()>;
+
+/// The block header type.
+pub type Header = generic::Header<BlockNumber, BlakeTwo256>;
+
+/// The block type.
+pub type Block = generic::Block<Header, UncheckedExtrinsic>;
+
+/// The transaction extensions checked for every signed transaction.
+pub type TxExtension = (
+	frame_system::CheckNonZeroSender<Runtime>,
+	frame_system::CheckSpecVersion<Runtime>,
+	frame_system::CheckTxVersion<Runtime>,
+	frame_system::CheckGenesis<Runtime>,
+	frame_system::CheckEra<Runtime>,
+	frame_system::CheckNonce<Runtime>,
+	frame_system::CheckWeight<Runtime>,
+	pallet_custom::CheckCounterBounds<Runtime>,
+);
+
+/// The extrinsic type.
+pub type UncheckedExtrinsic =
+	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, TxExtension>;
+
+/// The payload signed by the sender of a transaction.
+pub type SignedPayload = generic::SignedPayload<RuntimeCall, TxExtension>;
+
+/// Storage migrations run on runtime upgrade, in order.
+pub type Migrations = (
+	pallet_custom::migrations::v1::MigrateV0ToV1<Runtime>,
+	pallet_custom::migrations::v2::MigrateV1ToV2<Runtime>,
+	pallet_custom::migrations::v3::MigrateV2ToV3<Runtime, CounterValue>,
+);
+
+/// Dispatches incoming extrinsics to the pallets.
+pub type Executive = frame_executive::Executive<
+	Runtime,
+	Block,
+	frame_system::ChainContext<Runtime>,
+	Runtime,
+	AllPalletsWithSystem,
+	Migrations,
+>;
+
+/// The runtime version.
+#[sp_version::runtime_version]
+pub const VERSION: RuntimeVersion = RuntimeVersion {
+	spec_name: Cow::Borrowed("custom-runtime"),
+	impl_name: Cow::Borrowed("custom-runtime"),
+	authoring_version: 1,
+	spec_version: 1,
+	impl_version: 1,
+	apis: RUNTIME_API_VERSIONS,
+	transaction_version: 3,
+	system_version: 1,
+};
+
+/// One unit of the native token.
+pub const UNIT: Balance = 1_000_000_000_000;
+
+frame_support::construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		Balances: pallet_balances,
+		Preimage: pallet_preimage,
+		Scheduler: pallet_scheduler,
+		CustomPallet: pallet_custom,
+	}
+);
+
+parameter_types! {
+	pub const Version: RuntimeVersion = VERSION;
+}
+
+#[derive_impl(frame_system::config_preludes::SolochainDefaultConfig)]
+impl frame_system::Config for Runtime {
+	type Block = Block;
+	type Version = Version;
+	type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = UNIT / 100;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Runtime {
+	type Balance = Balance;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type MaxFreezes = VariantCountOf<RuntimeFreezeReason>;
+}
+
+parameter_types! {
+	pub const PreimageBaseDeposit: Balance = UNIT;
+	pub const PreimageByteDeposit: Balance = UNIT / 1_000;
+	pub const PreimageHoldReason: RuntimeHoldReason =
+		RuntimeHoldReason::Preimage(pallet_preimage::HoldReason::Preimage);
+}
+
+impl pallet_preimage::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_preimage::weights::SubstrateWeight<Runtime>;
+	type Currency = Balances;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	type Consideration = HoldConsideration<
+		AccountId,
+		Balances,
+		PreimageHoldReason,
+		LinearStoragePrice<PreimageBaseDeposit, PreimageByteDeposit, Balance>,
+	>;
+}
+
+parameter_types! {
+	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
+		<<Runtime as frame_system::Config>::BlockWeights as Get<BlockWeights>>::get().max_block;
+	pub const MaxScheduledPerBlock: u32 = 50;
+}
+
+impl pallet_scheduler::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type PalletsOrigin = OriginCaller;
+	type RuntimeCall = RuntimeCall;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type ScheduleOrigin = EnsureRoot<AccountId>;
+	type OriginPrivilegeCmp = EqualPrivilegeOnly;
+	type MaxScheduledPerBlock = MaxScheduledPerBlock;
+	type WeightInfo = pallet_scheduler::weights::SubstrateWeight<Runtime>;
+	type Preimages = Preimage;
+	type BlockNumberProvider = System;
+}
+
+impl frame_system::offchain::SigningTypes for Runtime {
+	type Public = <Signature as Verify>::Signer;
+	type Signature = Signature;
+}
+
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Runtime
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type Extrinsic = UncheckedExtrinsic;
+	type RuntimeCall = RuntimeCall;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Runtime
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_signed_transaction<
+		C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>,
+	>(
+		call: RuntimeCall,
+		public: <Signature as Verify>::Signer,
+		account: AccountId,
+		nonce: Nonce,
+	) -> Option<UncheckedExtrinsic> {
+		// The transaction is valid for the largest power of two of blocks that are still hashed.
+		let period = <<Runtime as frame_system::Config>::BlockHashCount as Get<BlockNumber>>::get()
+			.checked_next_power_of_two()
+			.map(|count| count / 2)
+			.unwrap_or(2) as u64;
+		let current_block = System::block_number().saturated_into::<u64>().saturating_sub(1);
+		let tx_ext: TxExtension = (
+			frame_system::CheckNonZeroSender::<Runtime>::new(),
+			frame_system::CheckSpecVersion::<Runtime>::new(),
+			frame_system::CheckTxVersion::<Runtime>::new(),
+			frame_system::CheckGenesis::<Runtime>::new(),
+			frame_system::CheckEra::<Runtime>::from(generic::Era::mortal(period, current_block)),
+			frame_system::CheckNonce::<Runtime>::from(nonce),
+			frame_system::CheckWeight::<Runtime>::new(),
+			pallet_custom::CheckCounterBounds::<Runtime>::new(),
+		);
+		let payload = SignedPayload::new(call, tx_ext).ok()?;
+		let signature = payload.using_encoded(|payload| C::sign(payload, public))?;
+		let (call, tx_ext, _) = payload.deconstruct();
+		Some(UncheckedExtrinsic::new_signed(call, MultiAddress::Id(account), signature, tx_ext))
+	}
+}
+
+parameter_types! {
+	pub const CounterMaxValue: CounterValue = 1_000;
+	pub const CounterMinValue: i64 = 0;
+	pub const MaxOpsPerCall: u32 = 16;
+	pub const MaxHistory: u32 = 100;
+	pub const MaxLeaderboard: u32 = 10;
+	pub const MaxNameLength: u32 = 32;
+	pub const CounterDeposit: Balance = UNIT;
+	pub const InteractionDeposit: Balance = UNIT / 10;
+	pub const MaxInteractionsPerPeriod: u32 = 10;
+	pub const RatePeriod: BlockNumber = 10;
+	// Counters are never reset, except in benchmark builds so the reset can be measured. The
+	// period is long enough not to change the era of any other benchmark.
+	pub const ResetPeriod: Option<BlockNumber> =
+		if cfg!(feature = "runtime-benchmarks") { Some(14_400) } else { None };
+	pub const ResetInteractions: bool = false;
+	pub const OffchainInterval: BlockNumber = 10;
+	// Feeless increments do not outrank fee-paying transactions.
+	pub const UnsignedPriority: TransactionPriority = TransactionPriority::MIN;
+	pub const UnsignedLongevity: TransactionLongevity = 64;
+	pub const MaxUnsignedPerBlock: u32 = 16;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+parameter_types! {
+	pub const BenchmarkParaId: pallet_custom::ParaId = pallet_custom::ParaId::new(2000);
+}
+
+impl pallet_custom::Config for Runtime {
+	type CounterValue = CounterValue;
+	type CounterMaxValue = CounterMaxValue;
+	type CounterMinValue = CounterMinValue;
+	type MaxOpsPerCall = MaxOpsPerCall;
+	type MaxHistory = MaxHistory;
+	type MaxLeaderboard = MaxLeaderboard;
+	type MaxNameLength = MaxNameLength;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type CounterDeposit = CounterDeposit;
+	type InteractionDeposit = InteractionDeposit;
+	type MaxInteractionsPerPeriod = MaxInteractionsPerPeriod;
+	type RatePeriod = RatePeriod;
+	type ResetPeriod = ResetPeriod;
+	type ResetInteractions = ResetInteractions;
+	type IncrementOrigin = EnsureSigned<AccountId>;
+	type DecrementOrigin = EnsureSigned<AccountId>;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type PalletsOrigin = OriginCaller;
+	type Scheduler = Scheduler;
+	type Preimages = Preimage;
+	type AuthorityId = pallet_custom::crypto::AuthorityId;
+	type OffchainInterval = OffchainInterval;
+	type UnsignedPriority = UnsignedPriority;
+	type UnsignedLongevity = UnsignedLongevity;
+	type MaxUnsignedPerBlock = MaxUnsignedPerBlock;
+	// A standalone chain receives no XCM, see the `pallet-custom-xcm-tests` crate for a parachain
+	// configuration. Benchmark builds let root act as a parachain to measure remote increments.
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type XcmOrigin = frame_support::traits::NeverEnsureOrigin<pallet_custom::ParaId>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type XcmOrigin = frame_system::EnsureRootWithSuccess<AccountId, BenchmarkParaId>;
+	type WeightInfo = pallet_custom::weights::SubstrateWeight<Runtime>;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+#[macro_use]
+mod benches {
+	frame_benchmarking::define_benchmarks!([pallet_custom, CustomPallet]);
+}
+
+impl_runtime_apis! {
+	impl sp_api::Core<Block> for Runtime {
+		fn version() -> RuntimeVersion {
+			VERSION
+		}
+
+		fn execute_block(block: Block) {
+			Executive::execute_block(block)
+		}
+
+		fn initialize_block(header: &<Block as BlockT>::Header) -> ExtrinsicInclusionMode {
+			Executive::initialize_block(header)
+		}
+	}
+
+	impl sp_api::Metadata<Block> for Runtime {
+		fn metadata() -> OpaqueMetadata {
+			OpaqueMetadata::new(Runtime::metadata().into())
+		}
+
+		fn metadata_at_version(version: u32) -> Option<OpaqueMetadata> {
+			Runtime::metadata_at_version(version)
+		}
+
+		fn metadata_versions() -> Vec<u32> {
+			Runtime::metadata_versions()
+		}
+	}
+
+	impl sp_block_builder::BlockBuilder<Block> for Runtime {
+		fn apply_extrinsic(extrinsic: <Block as BlockT>::Extrinsic) -> ApplyExtrinsicResult {
+			Executive::apply_extrinsic(extrinsic)
+		}
+
+		fn finalize_block() -> <Block as BlockT>::Header {
+			Executive::finalize_block()
+		}
+
+		fn inherent_extrinsics(
+			data: sp_inherents::InherentData,
+		) -> Vec<<Block as BlockT>::Extrinsic> {
+			data.create_extrinsics()
+		}
+
+		fn check_inherents(
+			block: Block,
+			data: sp_inherents::InherentData,
+		) -> sp_inherents::CheckInherentsResult {
+			data.check_extrinsics(&block)
+		}
+	}
+
+	impl sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block> for Runtime {
+		fn validate_transaction(
+			source: TransactionSource,
+			tx: <Block as BlockT>::Extrinsic,
+			block_hash: <Block as BlockT>::Hash,
+		) -> TransactionValidity {
+			Executive::validate_transaction(source, tx, block_hash)
+		}
+	}
+
+	impl sp_offchain::OffchainWorkerApi<Block> for Runtime {
+		fn offchain_worker(header: &<Block as BlockT>::Header) {
+			Executive::offchain_worker(header)
+		}
+	}
+
+	impl frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce> for Runtime {
+		fn account_nonce(account: AccountId) -> Nonce {
+			System::account_nonce(account)
+		}
+	}
+
+	impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
+		fn build_state(config: Vec<u8>) -> sp_genesis_builder::Result {
+			build_state::<RuntimeGenesisConfig>(config)
+		}
+
+		fn get_preset(id: &Option<sp_genesis_builder::PresetId>) -> Option<Vec<u8>> {
+			// The development preset, used by the benchmarks, is the default genesis config.
+			get_preset::<RuntimeGenesisConfig>(id, |id| {
+				(id.as_str() == sp_genesis_builder::DEV_RUNTIME_PRESET)
+					.then(|| get_preset::<RuntimeGenesisConfig>(&None, |_| None))
+					.flatten()
+			})
+		}
+
+		fn preset_names() -> Vec<sp_genesis_builder::PresetId> {
+			alloc::vec![sp_genesis_builder::DEV_RUNTIME_PRESET.into()]
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	impl frame_benchmarking::Benchmark<Block> for Runtime {
+		fn benchmark_metadata(extra: bool) -> (
+			Vec<frame_benchmarking::BenchmarkList>,
+			Vec<frame_support::traits::StorageInfo>,
+		) {
+			use frame_benchmarking::BenchmarkList;
+			use frame_support::traits::StorageInfoTrait;
+
+			let mut list = Vec::<BenchmarkList>::new();
+			list_benchmarks!(list, extra);
+
+			let storage_info = AllPalletsWithSystem::storage_info();
+			(list, storage_info)
+		}
+
+		fn dispatch_benchmark(
+			config: frame_benchmarking::BenchmarkConfig,
+		) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, alloc::string::String> {
+			use frame_benchmarking::BenchmarkBatch;
+			use frame_support::traits::WhitelistedStorageKeys;
+
+			let whitelist = AllPalletsWithSystem::whitelisted_storage_keys();
+			let mut batches = Vec::<BenchmarkBatch>::new();
+			let params = (&config, &whitelist);
+			add_benchmarks!(params, batches);
+
+			Ok(batches)
+		}
+	}
+
+	#[api_version(2)]
+	impl pallet_custom_runtime_api::CounterApi<Block, AccountId, CounterValue> for Runtime {
+		fn counter_value() -> CounterValue {
+			CustomPallet::counter_value()
+		}
+
+		fn max_value() -> CounterValue {
+			CustomPallet::max_value()
+		}
+
+		fn interactions_of(who: AccountId) -> u32 {
+			CustomPallet::interactions_of(&who)
+		}
+
+		fn top_interactors(limit: u32) -> Vec<(AccountId, u32)> {
+			CustomPallet::top_interactors(limit)
+		}
+
+		fn counter_value_at(at: BlockNumber) -> Option<CounterValue> {
+			CustomPallet::counter_value_at(at)
+		}
+	}
+}
@@ -0,0 +1,222 @@
+use crate::{
+	AccountId, Block, CounterValue, CustomPallet, Runtime, RuntimeCall, RuntimeOrigin, System,
+};
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok, dispatch::GetDispatchInfo};
+use frame_system::offchain::CreateSignedTransaction;
+use pallet_custom_runtime_api::runtime_decl_for_counter_api::CounterApiV2;
+use sp_core::{sr25519, Pair};
+use sp_keystore::{testing::MemoryKeystore, Keystore, KeystoreExt};
+use sp_runtime::{
+	generic::Preamble,
+	traits::{Applyable, Checkable, IdentifyAccount, ValidateUnsigned},
+	transaction_validity::{InvalidTransaction, TransactionSource},
+	BuildStorage, MultiAddress, MultiSignature, MultiSigner,
+};
+use std::sync::Arc;
+
+fn account(seed: u8) -> AccountId {
+	AccountId::new([seed; 32])
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	let mut storage = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Runtime> {
+		balances: (1..=3).map(|seed| (account(seed), 100 * crate::UNIT)).collect(),
+		..Default::default()
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn increment(who: u8, amount: CounterValue) {
+	assert_ok!(CustomPallet::increment(RuntimeOrigin::signed(account(who)), amount));
+}
+
+#[test]
+fn counter_value_defaults_to_zero() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(<Runtime as CounterApiV2<Block, AccountId, CounterValue>>::counter_value(), 0);
+		assert_eq!(<Runtime as CounterApiV2<Block, AccountId, CounterValue>>::max_value(), 1_000);
+	});
+}
+
+#[test]
+fn counter_value_follows_the_global_counter() {
+	new_test_ext().execute_with(|| {
+		increment(1, 5);
+		assert_eq!(<Runtime as CounterApiV2<Block, AccountId, CounterValue>>::counter_value(), 5);
+	});
+}
+
+#[test]
+fn counter_value_cannot_go_below_zero() {
+	new_test_ext().execute_with(|| {
+		increment(1, 2);
+		assert_noop!(
+			CustomPallet::decrement(RuntimeOrigin::signed(account(1)), 3),
+			pallet_custom::Error::<Runtime>::CounterValueBelowZero
+		);
+		assert_ok!(CustomPallet::decrement(RuntimeOrigin::signed(account(1)), 2));
+		assert_eq!(<Runtime as CounterApiV2<Block, AccountId, CounterValue>>::counter_value(), 0);
+	});
+}
+
+#[test]
+fn interactions_of_counts_mutations() {
+	new_test_ext().execute_with(|| {
+		increment(1, 1);
+		increment(1, 1);
+		assert_eq!(
+			<Runtime as CounterApiV2<Block, AccountId, CounterValue>>::interactions_of(account(1)),
+			2
+		);
+		assert_eq!(
+			<Runtime as CounterApiV2<Block, AccountId, CounterValue>>::interactions_of(account(2)),
+			0
+		);
+	});
+}
+
+#[test]
+fn top_interactors_are_sorted_and_limited() {
+	new_test_ext().execute_with(|| {
+		increment(1, 1);
+		increment(2, 1);
+		increment(2, 1);
+		increment(3, 1);
+		increment(3, 1);
+		increment(3, 1);
+
+		assert_eq!(
+			<Runtime as CounterApiV2<Block, AccountId, CounterValue>>::top_interactors(2),
+			vec![(account(3), 3), (account(2), 2)]
+		);
+		assert_eq!(
+			<Runtime as CounterApiV2<Block, AccountId, CounterValue>>::top_interactors(0),
+			vec![]
+		);
+	});
+}
+
+#[test]
+fn max_value_follows_the_override() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CustomPallet::set_max_value(RuntimeOrigin::root(), Some(2_000)));
+		assert_eq!(<Runtime as CounterApiV2<Block, AccountId, CounterValue>>::max_value(), 2_000);
+	});
+}
+
+#[test]
+fn counter_value_at_follows_the_history() {
+	new_test_ext().execute_with(|| {
+		increment(1, 5);
+		System::set_block_number(3);
+		increment(1, 2);
+
+		let value_at = <Runtime as CounterApiV2<Block, AccountId, CounterValue>>::counter_value_at;
+		assert_eq!(value_at(0), None);
+		assert_eq!(value_at(1), Some(5));
+		assert_eq!(value_at(2), Some(5));
+		assert_eq!(value_at(3), Some(7));
+		assert_eq!(value_at(10), Some(7));
+	});
+}
+
+#[test]
+fn offchain_worker_transactions_are_signed_with_the_local_key() {
+	let keystore = MemoryKeystore::new();
+	let public = keystore
+		.sr25519_generate_new(pallet_custom::crypto::KEY_TYPE, Some("//Alice"))
+		.unwrap();
+	let signer = MultiSigner::from(public);
+	let who = signer.clone().into_account();
+
+	let mut ext = new_test_ext();
+	ext.register_extension(KeystoreExt(Arc::new(keystore)));
+	ext.execute_with(|| {
+		let call = RuntimeCall::CustomPallet(pallet_custom::Call::submit_external_value {
+			counter_value: 7,
+		});
+		let tx = <Runtime as CreateSignedTransaction<pallet_custom::Call<Runtime>>>::create_signed_transaction::<
+			pallet_custom::crypto::AuthorityId,
+		>(call.clone(), signer, who.clone(), 0)
+		.unwrap();
+
+		assert!(matches!(&tx.preamble, Preamble::Signed(MultiAddress::Id(id), _, _) if *id == who));
+		let checked = tx.check(&frame_system::ChainContext::<Runtime>::default()).unwrap();
+		assert_eq!(checked.function, call);
+	});
+}
+
+#[test]
+fn unsigned_increments_are_signed_with_account_keys() {
+	new_test_ext().execute_with(|| {
+		let pair = sr25519::Pair::from_string("//Bob", None).unwrap();
+		let public = MultiSigner::from(pair.public());
+		let payload = pallet_custom::IncrementPayload {
+			public,
+			amount: 1,
+			nonce: 0,
+			genesis_hash: System::block_hash(0),
+		};
+		let signature = MultiSignature::from(pair.sign(&payload.encode()));
+		let call = pallet_custom::Call::<Runtime>::increment_unsigned { payload, signature };
+
+		assert!(CustomPallet::validate_unsigned(TransactionSource::External, &call).is_ok());
+
+		let pallet_custom::Call::increment_unsigned { payload, .. } = call else { unreachable!() };
+		let forged = MultiSignature::from(pair.sign(b"something else"));
+		let call =
+			pallet_custom::Call::<Runtime>::increment_unsigned { payload, signature: forged };
+		assert_eq!(
+			CustomPallet::validate_unsigned(TransactionSource::External, &call),
+			InvalidTransaction::BadProof.into()
+		);
+	});
+}
+
+#[test]
+fn out_of_bounds_increments_are_rejected_before_inclusion() {
+	let keystore = MemoryKeystore::new();
+	let public = keystore
+		.sr25519_generate_new(pallet_custom::crypto::KEY_TYPE, Some("//Alice"))
+		.unwrap();
+	let signer = MultiSigner::from(public);
+	let who = signer.clone().into_account();
+
+	let mut ext = new_test_ext();
+	ext.register_extension(KeystoreExt(Arc::new(keystore)));
+	ext.execute_with(|| {
+		System::inc_providers(&who);
+		let validate = |amount| {
+			let call = RuntimeCall::CustomPallet(pallet_custom::Call::increment {
+				amount_to_increment: amount,
+			});
+			let tx = <Runtime as CreateSignedTransaction<pallet_custom::Call<Runtime>>>::create_signed_transaction::<
+				pallet_custom::crypto::AuthorityId,
+			>(call, signer.clone(), who.clone(), 0)
+			.unwrap();
+			let len = tx.encoded_size();
+			let checked = tx.check(&frame_system::ChainContext::<Runtime>::default()).unwrap();
+			checked.validate::<Runtime>(
+				TransactionSource::External,
+				&checked.get_dispatch_info(),
+				len,
+			)
+		};
+
+		assert!(validate(1_000).is_ok());
+		assert_eq!(
+			validate(1_001),
+			InvalidTransaction::Custom(
+				pallet_custom::extensions::ValidityError::CounterValueExceedsMax as u8
+			)
+			.into()
+		);
+	});
+}